@@ -1,46 +1,192 @@
 // AUTO GENERATED FILE, DO NOT EDIT.
-// Generated by `flutter_rust_bridge`.
-
-// ignore_for_file: non_constant_identifier_names, unused_element, duplicate_ignore, directives_ordering, curly_braces_in_flow_control_structures, unnecessary_lambdas, slash_for_doc_comments, prefer_const_literals_to_create_immutables, implicit_dynamic_list_literal, duplicate_import, unused_import
-
-import 'dart:convert';
-import 'dart:typed_data';
+// Generated by `flutter_rust_bridge`@ 1.82.6.
+// ignore_for_file: non_constant_identifier_names, unused_element, duplicate_ignore, directives_ordering, curly_braces_in_flow_control_structures, unnecessary_lambdas, slash_for_doc_comments, prefer_const_literals_to_create_immutables, implicit_dynamic_list_literal, duplicate_import, unused_import, unnecessary_import, prefer_single_quotes, prefer_const_constructors, use_super_parameters, always_use_package_imports, annotate_overrides, invalid_use_of_protected_member, constant_identifier_names, invalid_use_of_internal_member, prefer_is_empty, unnecessary_const
 
 import 'dart:convert';
+import 'dart:async';
 import 'dart:typed_data';
+import 'package:meta/meta.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge.dart';
-import 'dart:ffi' as ffi;
+
+import 'bridge_generated.io.dart';
 
 abstract class Turtlebot2 {
-  Stream<String> spawnTurtlebot({dynamic hint});
+  Future<int> newTurtlebot({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kNewTurtlebotConstMeta;
+
+  Stream<String> spawnTurtlebot({required int handle, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSpawnTurtlebotConstMeta;
+
+  Future<void> removeTurtlebot({required int handle, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRemoveTurtlebotConstMeta;
+
+  Future<List<Feedback>> receiveFromTurtlebot(
+      {required int handle, dynamic hint});
 
-  Future<List<Feedback>> receiveFromTurtlebot({dynamic hint});
+  FlutterRustBridgeTaskConstMeta get kReceiveFromTurtlebotConstMeta;
+
+  Future<List<DeviceEvent>> receiveDeviceEvents(
+      {required int handle, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kReceiveDeviceEventsConstMeta;
+
+  Future<LatencyStats> latencyCommand({required int handle, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kLatencyCommandConstMeta;
+
+  Future<DecoderStats> decoderStatsCommand({required int handle, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kDecoderStatsCommandConstMeta;
+
+  Future<BatteryStatus> batteryStatusCommand(
+      {required int handle, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kBatteryStatusCommandConstMeta;
 
   Future<List<String>> searchPortCommand({dynamic hint});
 
-  Future<void> openPortCommand({required String serialPort, dynamic hint});
+  FlutterRustBridgeTaskConstMeta get kSearchPortCommandConstMeta;
+
+  Future<List<PortDescriptor>> searchPortDescriptorsCommand({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSearchPortDescriptorsCommandConstMeta;
+
+  Future<void> openPortCommand(
+      {required int handle, required String serialPort, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kOpenPortCommandConstMeta;
+
+  Future<void> openPortWithConfigCommand(
+      {required int handle,
+      required String serialPort,
+      required ConnectionConfig config,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kOpenPortWithConfigCommandConstMeta;
+
+  Future<void> closePortCommand({required int handle, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kClosePortCommandConstMeta;
+
+  Future<void> setEventSoundPolicyCommand(
+      {required int handle, required EventSoundPolicy policy, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSetEventSoundPolicyCommandConstMeta;
+
+  Future<void> setReconnectPolicyCommand(
+      {required int handle, required ReconnectPolicy policy, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSetReconnectPolicyCommandConstMeta;
+
+  Future<String> startCaptureCommand(
+      {required int handle, required String path, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kStartCaptureCommandConstMeta;
+
+  Future<void> stopCaptureCommand({required int handle, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kStopCaptureCommandConstMeta;
+
+  Future<void> replayModeCommand(
+      {required int handle,
+      required ReplayMode mode,
+      required double speed,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kReplayModeCommandConstMeta;
+
+  Future<void> replayPauseCommand(
+      {required int handle, required bool paused, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kReplayPauseCommandConstMeta;
 
-  Future<void> closePortCommand({dynamic hint});
+  Future<void> replaySeekCommand(
+      {required int handle, required int positionMs, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kReplaySeekCommandConstMeta;
+
+  Future<void> replayStepCommand({required int handle, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kReplayStepCommandConstMeta;
+
+  Future<void> driveCommand(
+      {required int handle,
+      required double linear,
+      required double angular,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kDriveCommandConstMeta;
 
   Future<void> baseControlCommand(
-      {required int speed, required int radius, dynamic hint});
+      {required int handle,
+      required int speed,
+      required int radius,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kBaseControlCommandConstMeta;
 
   Future<void> soundCommand(
-      {required int freq,
-      required int amp,
-      required int duration,
+      {required int handle,
+      required double frequency,
+      required int durationMs,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSoundCommandConstMeta;
+
+  Future<void> noteCommand(
+      {required int handle,
+      required String note,
+      required int durationMs,
       dynamic hint});
 
-  Future<void> soundSequenceCommand({required int seq, dynamic hint});
+  FlutterRustBridgeTaskConstMeta get kNoteCommandConstMeta;
+
+  Future<void> playTuneCommand(
+      {required int handle,
+      required List<TuneNote> notes,
+      required double tempoBpm,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kPlayTuneCommandConstMeta;
+
+  Future<void> playRtttlCommand(
+      {required int handle, required String rtttl, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kPlayRtttlCommandConstMeta;
+
+  Future<TuneProgress> tuneProgressCommand({required int handle, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kTuneProgressCommandConstMeta;
+
+  Future<void> setTuneTempoCommand(
+      {required int handle, required double tempoBpm, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSetTuneTempoCommandConstMeta;
+
+  Future<void> stopTuneCommand({required int handle, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kStopTuneCommandConstMeta;
+
+  Future<void> soundSequenceCommand(
+      {required int handle, required SoundSequence seq, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSoundSequenceCommandConstMeta;
 
   Future<void> requestExtraCommand(
-      {required bool hwVer,
+      {required int handle,
+      required bool hwVer,
       required bool fwVer,
       required bool udid,
       dynamic hint});
 
+  FlutterRustBridgeTaskConstMeta get kRequestExtraCommandConstMeta;
+
   Future<void> generalPurposeOutputCommand(
-      {required bool dOutCh0,
+      {required int handle,
+      required bool dOutCh0,
       required bool dOutCh1,
       required bool dOutCh2,
       required bool dOutCh3,
@@ -54,14 +200,22 @@ abstract class Turtlebot2 {
       required bool greenLed2,
       dynamic hint});
 
+  FlutterRustBridgeTaskConstMeta get kGeneralPurposeOutputCommandConstMeta;
+
   Future<void> setControllerGainCommand(
-      {required bool isUserConfigured,
+      {required int handle,
+      required bool isUserConfigured,
       required int p,
       required double i,
       required int d,
       dynamic hint});
 
-  Future<void> getControllerGain({dynamic hint});
+  FlutterRustBridgeTaskConstMeta get kSetControllerGainCommandConstMeta;
+
+  Future<void> getControllerGain({required int handle, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kGetControllerGainConstMeta;
+
 }
 
 class BasicSensor {
@@ -77,9 +231,16 @@ class BasicSensor {
   final int button;
   final int charger;
   final int battery;
+  final double batteryVolts;
   final int overcurrentFlags;
-
-  BasicSensor({
+  final BumperFlags bumpers;
+  final WheelDropFlags wheelDrops;
+  final CliffFlags cliffs;
+  final ButtonFlags buttons;
+  final ChargerState chargerState;
+  final OvercurrentFlags overcurrents;
+
+  const BasicSensor({
     required this.valid,
     required this.timeStamp,
     required this.bumper,
@@ -92,17 +253,82 @@ class BasicSensor {
     required this.button,
     required this.charger,
     required this.battery,
+    required this.batteryVolts,
     required this.overcurrentFlags,
+    required this.bumpers,
+    required this.wheelDrops,
+    required this.cliffs,
+    required this.buttons,
+    required this.chargerState,
+    required this.overcurrents,
+  });
+}
+
+enum BatteryState {
+  Discharging,
+  Charging,
+  Charged,
+}
+
+class BatteryStatus {
+  final bool valid;
+  final double volts;
+  final double smoothedVolts;
+  final int cells;
+  final double percentage;
+  final BatteryState state;
+  final double timeRemainingSec;
+
+  const BatteryStatus({
+    required this.valid,
+    required this.volts,
+    required this.smoothedVolts,
+    required this.cells,
+    required this.percentage,
+    required this.state,
+    required this.timeRemainingSec,
+  });
+}
+
+class BumperFlags {
+  final bool left;
+  final bool center;
+  final bool right;
+
+  const BumperFlags({
+    required this.left,
+    required this.center,
+    required this.right,
   });
 }
 
+class ButtonFlags {
+  final bool b0;
+  final bool b1;
+  final bool b2;
+
+  const ButtonFlags({
+    required this.b0,
+    required this.b1,
+    required this.b2,
+  });
+}
+
+enum ChargerState {
+  Discharging,
+  DockingCharged,
+  DockingCharging,
+  AdapterCharged,
+  AdapterCharging,
+}
+
 class Cliff {
   final bool valid;
   final int rightCliffSensor;
   final int centralCliffSensor;
   final int leftCliffSensor;
 
-  Cliff({
+  const Cliff({
     required this.valid,
     required this.rightCliffSensor,
     required this.centralCliffSensor,
@@ -110,6 +336,36 @@ class Cliff {
   });
 }
 
+class CliffFlags {
+  final bool left;
+  final bool center;
+  final bool right;
+
+  const CliffFlags({
+    required this.left,
+    required this.center,
+    required this.right,
+  });
+}
+
+class ConnectionConfig {
+  final int baudRate;
+  final int readTimeoutMs;
+  final ReadStrategy readStrategy;
+  final int pollIntervalMs;
+  final int bufferSize;
+  final FlowControl flowControl;
+
+  const ConnectionConfig({
+    required this.baudRate,
+    required this.readTimeoutMs,
+    required this.readStrategy,
+    required this.pollIntervalMs,
+    required this.bufferSize,
+    required this.flowControl,
+  });
+}
+
 class ControllerInfo {
   final bool valid;
   final int isUserConfigured;
@@ -117,7 +373,7 @@ class ControllerInfo {
   final int iGain;
   final int dGain;
 
-  ControllerInfo({
+  const ControllerInfo({
     required this.valid,
     required this.isUserConfigured,
     required this.pGain,
@@ -130,11 +386,45 @@ class Current {
   final bool valid;
   final int leftMotor;
   final int rightMotor;
+  final double leftMotorAmps;
+  final double rightMotorAmps;
 
-  Current({
+  const Current({
     required this.valid,
     required this.leftMotor,
     required this.rightMotor,
+    required this.leftMotorAmps,
+    required this.rightMotorAmps,
+  });
+}
+
+class DecoderStats {
+  final int framesOk;
+  final int crcFailures;
+  final int resyncs;
+  final int bytesDiscarded;
+  final int unknownIds;
+  final int truncatedSubPayloads;
+  final String lastError;
+
+  const DecoderStats({
+    required this.framesOk,
+    required this.crcFailures,
+    required this.resyncs,
+    required this.bytesDiscarded,
+    required this.unknownIds,
+    required this.truncatedSubPayloads,
+    required this.lastError,
+  });
+}
+
+class DeviceEvent {
+  final bool attached;
+  final PortDescriptor port;
+
+  const DeviceEvent({
+    required this.attached,
+    required this.port,
   });
 }
 
@@ -144,7 +434,7 @@ class DockingIR {
   final int centralSignal;
   final int leftSignal;
 
-  DockingIR({
+  const DockingIR({
     required this.valid,
     required this.rightSignal,
     required this.centralSignal,
@@ -152,6 +442,52 @@ class DockingIR {
   });
 }
 
+class EncoderTicks {
+  final bool valid;
+  final int left;
+  final int right;
+  final int leftDelta;
+  final int rightDelta;
+  final int timeStampMs;
+  final int dtMs;
+
+  const EncoderTicks({
+    required this.valid,
+    required this.left,
+    required this.right,
+    required this.leftDelta,
+    required this.rightDelta,
+    required this.timeStampMs,
+    required this.dtMs,
+  });
+}
+
+class EventSoundPolicy {
+  final bool enabled;
+  final bool onConnected;
+  final SoundSequence connected;
+  final bool onBump;
+  final SoundSequence bump;
+  final bool onLowBattery;
+  final SoundSequence lowBattery;
+  final double lowBatteryPercentage;
+  final bool onDocking;
+  final SoundSequence docking;
+
+  const EventSoundPolicy({
+    required this.enabled,
+    required this.onConnected,
+    required this.connected,
+    required this.onBump,
+    required this.bump,
+    required this.onLowBattery,
+    required this.lowBattery,
+    required this.lowBatteryPercentage,
+    required this.onDocking,
+    required this.docking,
+  });
+}
+
 class Feedback {
   final String epochTimeStamp;
   final BasicSensor basicSensor;
@@ -165,8 +501,11 @@ class Feedback {
   final GeneralPurposeInput generalPurposeInput;
   final UniqueDeviceId uniqueDeviceId;
   final ControllerInfo controllerInfo;
+  final BatteryStatus battery;
+  final EncoderTicks encoders;
+  final List<UnknownSubPayload> unknownSubPayloads;
 
-  Feedback({
+  const Feedback({
     required this.epochTimeStamp,
     required this.basicSensor,
     required this.dockingIr,
@@ -179,6 +518,9 @@ class Feedback {
     required this.generalPurposeInput,
     required this.uniqueDeviceId,
     required this.controllerInfo,
+    required this.battery,
+    required this.encoders,
+    required this.unknownSubPayloads,
   });
 }
 
@@ -188,7 +530,7 @@ class FirmwareVersion {
   final int minor;
   final int major;
 
-  FirmwareVersion({
+  const FirmwareVersion({
     required this.valid,
     required this.patch,
     required this.minor,
@@ -196,6 +538,12 @@ class FirmwareVersion {
   });
 }
 
+enum FlowControl {
+  None,
+  Software,
+  Hardware,
+}
+
 class GeneralPurposeInput {
   final bool valid;
   final int dCh0;
@@ -204,7 +552,7 @@ class GeneralPurposeInput {
   final int aCh2;
   final int aCh3;
 
-  GeneralPurposeInput({
+  const GeneralPurposeInput({
     required this.valid,
     required this.dCh0,
     required this.aCh0,
@@ -218,29 +566,39 @@ class Gyro {
   final bool valid;
   final int frameId;
   final int followedDataLength;
-  final int x0;
-  final int y0;
-  final int z0;
-  final int x1;
-  final int y1;
-  final int z1;
-  final int x2;
-  final int y2;
-  final int z2;
-
-  Gyro({
+  final List<GyroSample> samples;
+  final double xRadPerSec;
+  final double yRadPerSec;
+  final double zRadPerSec;
+
+  const Gyro({
     required this.valid,
     required this.frameId,
     required this.followedDataLength,
-    required this.x0,
-    required this.y0,
-    required this.z0,
-    required this.x1,
-    required this.y1,
-    required this.z1,
-    required this.x2,
-    required this.y2,
-    required this.z2,
+    required this.samples,
+    required this.xRadPerSec,
+    required this.yRadPerSec,
+    required this.zRadPerSec,
+  });
+}
+
+class GyroSample {
+  final int frameId;
+  final int x;
+  final int y;
+  final int z;
+  final double xRadPerSec;
+  final double yRadPerSec;
+  final double zRadPerSec;
+
+  const GyroSample({
+    required this.frameId,
+    required this.x,
+    required this.y,
+    required this.z,
+    required this.xRadPerSec,
+    required this.yRadPerSec,
+    required this.zRadPerSec,
   });
 }
 
@@ -250,7 +608,7 @@ class HardwareVersion {
   final int minor;
   final int major;
 
-  HardwareVersion({
+  const HardwareVersion({
     required this.valid,
     required this.patch,
     required this.minor,
@@ -262,11 +620,136 @@ class InertialSensor {
   final bool valid;
   final int angle;
   final int angleRate;
+  final double angleDeg;
+  final double angleRad;
+  final double angleRateDegPerSec;
+  final double angleRateRadPerSec;
 
-  InertialSensor({
+  const InertialSensor({
     required this.valid,
     required this.angle,
     required this.angleRate,
+    required this.angleDeg,
+    required this.angleRad,
+    required this.angleRateDegPerSec,
+    required this.angleRateRadPerSec,
+  });
+}
+
+class LatencyStats {
+  final int samples;
+  final double lastMs;
+  final double meanMs;
+  final double maxMs;
+
+  const LatencyStats({
+    required this.samples,
+    required this.lastMs,
+    required this.meanMs,
+    required this.maxMs,
+  });
+}
+
+enum MatchConfidence {
+  Low,
+  Medium,
+  High,
+}
+
+class OvercurrentFlags {
+  final bool left;
+  final bool right;
+
+  const OvercurrentFlags({
+    required this.left,
+    required this.right,
+  });
+}
+
+class PortDescriptor {
+  final String portName;
+  final int vid;
+  final int pid;
+  final String manufacturer;
+  final String product;
+  final String serialNumber;
+  final MatchConfidence confidence;
+
+  const PortDescriptor({
+    required this.portName,
+    required this.vid,
+    required this.pid,
+    required this.manufacturer,
+    required this.product,
+    required this.serialNumber,
+    required this.confidence,
+  });
+}
+
+enum ReadStrategy {
+  Poll,
+  Blocking,
+}
+
+class ReconnectPolicy {
+  final bool enabled;
+  final int retryIntervalMs;
+  final int maxRetryIntervalMs;
+  final double backoffMultiplier;
+  final int maxAttempts;
+  final bool relocateBySerialNumber;
+
+  const ReconnectPolicy({
+    required this.enabled,
+    required this.retryIntervalMs,
+    required this.maxRetryIntervalMs,
+    required this.backoffMultiplier,
+    required this.maxAttempts,
+    required this.relocateBySerialNumber,
+  });
+}
+
+enum ReplayMode {
+  RealTime,
+  Accelerated,
+  SingleStep,
+}
+
+enum SoundSequence {
+  On,
+  Off,
+  Recharge,
+  Button,
+  Error,
+  CleaningStart,
+  CleaningEnd,
+}
+
+class TuneNote {
+  final String note;
+  final double beats;
+
+  const TuneNote({
+    required this.note,
+    required this.beats,
+  });
+}
+
+class TuneProgress {
+  final bool playing;
+  final String name;
+  final int step;
+  final int steps;
+  final double elapsedMs;
+  final double durationMs;
+
+  const TuneProgress({
+    required this.playing,
+    required this.name,
+    required this.step,
+    required this.steps,
+    required this.elapsedMs,
+    required this.durationMs,
   });
 }
 
@@ -276,7 +759,7 @@ class UniqueDeviceId {
   final int udid1;
   final int udid2;
 
-  UniqueDeviceId({
+  const UniqueDeviceId({
     required this.valid,
     required this.udid0,
     required this.udid1,
@@ -284,837 +767,1351 @@ class UniqueDeviceId {
   });
 }
 
-class Turtlebot2Impl extends FlutterRustBridgeBase<Turtlebot2Wire>
-    implements Turtlebot2 {
-  factory Turtlebot2Impl(ffi.DynamicLibrary dylib) =>
-      Turtlebot2Impl.raw(Turtlebot2Wire(dylib));
-
-  Turtlebot2Impl.raw(Turtlebot2Wire inner) : super(inner);
-
-  Stream<String> spawnTurtlebot({dynamic hint}) =>
-      executeStream(FlutterRustBridgeTask(
-        callFfi: (port) => inner.wire_spawn_turtlebot(port),
-        parseSuccessData: _wire2api_String,
-        constMeta: const FlutterRustBridgeTaskConstMeta(
-          debugName: "spawn_turtlebot",
-          argNames: [],
-        ),
-        argValues: [],
-        hint: hint,
-      ));
-
-  Future<List<Feedback>> receiveFromTurtlebot({dynamic hint}) =>
-      executeNormal(FlutterRustBridgeTask(
-        callFfi: (port) => inner.wire_receive_from_turtlebot(port),
-        parseSuccessData: _wire2api_list_feedback,
-        constMeta: const FlutterRustBridgeTaskConstMeta(
-          debugName: "receive_from_turtlebot",
-          argNames: [],
-        ),
-        argValues: [],
-        hint: hint,
-      ));
-
-  Future<List<String>> searchPortCommand({dynamic hint}) =>
-      executeNormal(FlutterRustBridgeTask(
-        callFfi: (port) => inner.wire_search_port_command(port),
-        parseSuccessData: _wire2api_StringList,
-        constMeta: const FlutterRustBridgeTaskConstMeta(
-          debugName: "search_port_command",
-          argNames: [],
-        ),
-        argValues: [],
-        hint: hint,
-      ));
-
-  Future<void> openPortCommand({required String serialPort, dynamic hint}) =>
-      executeNormal(FlutterRustBridgeTask(
-        callFfi: (port) =>
-            inner.wire_open_port_command(port, _api2wire_String(serialPort)),
-        parseSuccessData: _wire2api_unit,
-        constMeta: const FlutterRustBridgeTaskConstMeta(
-          debugName: "open_port_command",
-          argNames: ["serialPort"],
-        ),
-        argValues: [serialPort],
-        hint: hint,
-      ));
-
-  Future<void> closePortCommand({dynamic hint}) =>
-      executeNormal(FlutterRustBridgeTask(
-        callFfi: (port) => inner.wire_close_port_command(port),
-        parseSuccessData: _wire2api_unit,
-        constMeta: const FlutterRustBridgeTaskConstMeta(
-          debugName: "close_port_command",
-          argNames: [],
-        ),
-        argValues: [],
-        hint: hint,
-      ));
+class UnknownSubPayload {
+  final int id;
+  final Uint8List data;
 
-  Future<void> baseControlCommand(
-          {required int speed, required int radius, dynamic hint}) =>
-      executeNormal(FlutterRustBridgeTask(
-        callFfi: (port) => inner.wire_base_control_command(
-            port, _api2wire_u16(speed), _api2wire_u16(radius)),
-        parseSuccessData: _wire2api_unit,
-        constMeta: const FlutterRustBridgeTaskConstMeta(
-          debugName: "base_control_command",
-          argNames: ["speed", "radius"],
-        ),
-        argValues: [speed, radius],
-        hint: hint,
-      ));
+  const UnknownSubPayload({
+    required this.id,
+    required this.data,
+  });
+}
 
-  Future<void> soundCommand(
-          {required int freq,
-          required int amp,
-          required int duration,
-          dynamic hint}) =>
-      executeNormal(FlutterRustBridgeTask(
-        callFfi: (port) => inner.wire_sound_command(port, _api2wire_u8(freq),
-            _api2wire_u8(amp), _api2wire_u8(duration)),
-        parseSuccessData: _wire2api_unit,
-        constMeta: const FlutterRustBridgeTaskConstMeta(
-          debugName: "sound_command",
-          argNames: ["freq", "amp", "duration"],
-        ),
-        argValues: [freq, amp, duration],
-        hint: hint,
-      ));
-
-  Future<void> soundSequenceCommand({required int seq, dynamic hint}) =>
-      executeNormal(FlutterRustBridgeTask(
-        callFfi: (port) =>
-            inner.wire_sound_sequence_command(port, _api2wire_u8(seq)),
-        parseSuccessData: _wire2api_unit,
-        constMeta: const FlutterRustBridgeTaskConstMeta(
-          debugName: "sound_sequence_command",
-          argNames: ["seq"],
-        ),
-        argValues: [seq],
-        hint: hint,
-      ));
+class WheelDropFlags {
+  final bool left;
+  final bool right;
 
-  Future<void> requestExtraCommand(
-          {required bool hwVer,
-          required bool fwVer,
-          required bool udid,
-          dynamic hint}) =>
-      executeNormal(FlutterRustBridgeTask(
-        callFfi: (port) =>
-            inner.wire_request_extra_command(port, hwVer, fwVer, udid),
-        parseSuccessData: _wire2api_unit,
-        constMeta: const FlutterRustBridgeTaskConstMeta(
-          debugName: "request_extra_command",
-          argNames: ["hwVer", "fwVer", "udid"],
-        ),
-        argValues: [hwVer, fwVer, udid],
-        hint: hint,
-      ));
+  const WheelDropFlags({
+    required this.left,
+    required this.right,
+  });
+}
 
-  Future<void> generalPurposeOutputCommand(
-          {required bool dOutCh0,
-          required bool dOutCh1,
-          required bool dOutCh2,
-          required bool dOutCh3,
-          required bool power3V3,
-          required bool power5V0,
-          required bool power12V5A,
-          required bool power12V1A5,
-          required bool redLed1,
-          required bool redLed2,
-          required bool greenLed1,
-          required bool greenLed2,
-          dynamic hint}) =>
-      executeNormal(FlutterRustBridgeTask(
-        callFfi: (port) => inner.wire_general_purpose_output_command(
-            port,
-            dOutCh0,
-            dOutCh1,
-            dOutCh2,
-            dOutCh3,
-            power3V3,
-            power5V0,
-            power12V5A,
-            power12V1A5,
-            redLed1,
-            redLed2,
-            greenLed1,
-            greenLed2),
-        parseSuccessData: _wire2api_unit,
-        constMeta: const FlutterRustBridgeTaskConstMeta(
-          debugName: "general_purpose_output_command",
-          argNames: [
-            "dOutCh0",
-            "dOutCh1",
-            "dOutCh2",
-            "dOutCh3",
-            "power3V3",
-            "power5V0",
-            "power12V5A",
-            "power12V1A5",
-            "redLed1",
-            "redLed2",
-            "greenLed1",
-            "greenLed2"
-          ],
-        ),
-        argValues: [
-          dOutCh0,
-          dOutCh1,
-          dOutCh2,
-          dOutCh3,
-          power3V3,
-          power5V0,
-          power12V5A,
-          power12V1A5,
-          redLed1,
-          redLed2,
-          greenLed1,
-          greenLed2
-        ],
-        hint: hint,
-      ));
+class Turtlebot2Impl implements Turtlebot2 {
+  final Turtlebot2Platform _platform;
+  factory Turtlebot2Impl(ExternalLibrary dylib) =>
+      Turtlebot2Impl.raw(Turtlebot2Platform(dylib));
+
+  /// Only valid on web/WASM platforms.
+  factory Turtlebot2Impl.wasm(FutureOr<WasmModule> module) =>
+      Turtlebot2Impl(module as ExternalLibrary);
+  Turtlebot2Impl.raw(this._platform);
+  Future<int> newTurtlebot({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_new_turtlebot(port_),
+      parseSuccessData: _wire2api_u32,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kNewTurtlebotConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
 
-  Future<void> setControllerGainCommand(
-          {required bool isUserConfigured,
-          required int p,
-          required double i,
-          required int d,
-          dynamic hint}) =>
-      executeNormal(FlutterRustBridgeTask(
-        callFfi: (port) => inner.wire_set_controller_gain_command(
-            port,
-            isUserConfigured,
-            _api2wire_u32(p),
-            _api2wire_f32(i),
-            _api2wire_u32(d)),
-        parseSuccessData: _wire2api_unit,
-        constMeta: const FlutterRustBridgeTaskConstMeta(
-          debugName: "set_controller_gain_command",
-          argNames: ["isUserConfigured", "p", "i", "d"],
-        ),
-        argValues: [isUserConfigured, p, i, d],
-        hint: hint,
-      ));
+  FlutterRustBridgeTaskConstMeta get kNewTurtlebotConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "new_turtlebot",
+        argNames: [],
+      );
+
+  Stream<String> spawnTurtlebot({required int handle, dynamic hint}) {
+    var arg0 = api2wire_u32(handle);
+    return _platform.executeStream(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_spawn_turtlebot(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kSpawnTurtlebotConstMeta,
+      argValues: [handle],
+      hint: hint,
+    ));
+  }
 
-  Future<void> getControllerGain({dynamic hint}) =>
-      executeNormal(FlutterRustBridgeTask(
-        callFfi: (port) => inner.wire_get_controller_gain(port),
-        parseSuccessData: _wire2api_unit,
-        constMeta: const FlutterRustBridgeTaskConstMeta(
-          debugName: "get_controller_gain",
-          argNames: [],
-        ),
-        argValues: [],
-        hint: hint,
-      ));
+  FlutterRustBridgeTaskConstMeta get kSpawnTurtlebotConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "spawn_turtlebot",
+        argNames: ["handle"],
+      );
+
+  Future<void> removeTurtlebot({required int handle, dynamic hint}) {
+    var arg0 = api2wire_u32(handle);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_remove_turtlebot(port_, arg0),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRemoveTurtlebotConstMeta,
+      argValues: [handle],
+      hint: hint,
+    ));
+  }
 
-  // Section: api2wire
-  ffi.Pointer<wire_uint_8_list> _api2wire_String(String raw) {
-    return _api2wire_uint_8_list(utf8.encoder.convert(raw));
+  FlutterRustBridgeTaskConstMeta get kRemoveTurtlebotConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "remove_turtlebot",
+        argNames: ["handle"],
+      );
+
+  Future<List<Feedback>> receiveFromTurtlebot(
+      {required int handle, dynamic hint}) {
+    var arg0 = api2wire_u32(handle);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_receive_from_turtlebot(port_, arg0),
+      parseSuccessData: _wire2api_list_feedback,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kReceiveFromTurtlebotConstMeta,
+      argValues: [handle],
+      hint: hint,
+    ));
   }
 
-  int _api2wire_bool(bool raw) {
-    return raw ? 1 : 0;
+  FlutterRustBridgeTaskConstMeta get kReceiveFromTurtlebotConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "receive_from_turtlebot",
+        argNames: ["handle"],
+      );
+
+  Future<List<DeviceEvent>> receiveDeviceEvents(
+      {required int handle, dynamic hint}) {
+    var arg0 = api2wire_u32(handle);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_receive_device_events(port_, arg0),
+      parseSuccessData: _wire2api_list_device_event,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kReceiveDeviceEventsConstMeta,
+      argValues: [handle],
+      hint: hint,
+    ));
   }
 
-  double _api2wire_f32(double raw) {
-    return raw;
+  FlutterRustBridgeTaskConstMeta get kReceiveDeviceEventsConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "receive_device_events",
+        argNames: ["handle"],
+      );
+
+  Future<LatencyStats> latencyCommand({required int handle, dynamic hint}) {
+    var arg0 = api2wire_u32(handle);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_latency_command(port_, arg0),
+      parseSuccessData: _wire2api_latency_stats,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kLatencyCommandConstMeta,
+      argValues: [handle],
+      hint: hint,
+    ));
   }
 
-  int _api2wire_u16(int raw) {
-    return raw;
+  FlutterRustBridgeTaskConstMeta get kLatencyCommandConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "latency_command",
+        argNames: ["handle"],
+      );
+
+  Future<DecoderStats> decoderStatsCommand(
+      {required int handle, dynamic hint}) {
+    var arg0 = api2wire_u32(handle);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_decoder_stats_command(port_, arg0),
+      parseSuccessData: _wire2api_decoder_stats,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kDecoderStatsCommandConstMeta,
+      argValues: [handle],
+      hint: hint,
+    ));
   }
 
-  int _api2wire_u32(int raw) {
-    return raw;
+  FlutterRustBridgeTaskConstMeta get kDecoderStatsCommandConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "decoder_stats_command",
+        argNames: ["handle"],
+      );
+
+  Future<BatteryStatus> batteryStatusCommand(
+      {required int handle, dynamic hint}) {
+    var arg0 = api2wire_u32(handle);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_battery_status_command(port_, arg0),
+      parseSuccessData: _wire2api_battery_status,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kBatteryStatusCommandConstMeta,
+      argValues: [handle],
+      hint: hint,
+    ));
   }
 
-  int _api2wire_u8(int raw) {
-    return raw;
+  FlutterRustBridgeTaskConstMeta get kBatteryStatusCommandConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "battery_status_command",
+        argNames: ["handle"],
+      );
+
+  Future<List<String>> searchPortCommand({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_search_port_command(port_),
+      parseSuccessData: _wire2api_StringList,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kSearchPortCommandConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
   }
 
-  ffi.Pointer<wire_uint_8_list> _api2wire_uint_8_list(Uint8List raw) {
-    final ans = inner.new_uint_8_list(raw.length);
-    ans.ref.ptr.asTypedList(raw.length).setAll(0, raw);
-    return ans;
+  FlutterRustBridgeTaskConstMeta get kSearchPortCommandConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "search_port_command",
+        argNames: [],
+      );
+
+  Future<List<PortDescriptor>> searchPortDescriptorsCommand({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_search_port_descriptors_command(port_),
+      parseSuccessData: _wire2api_list_port_descriptor,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kSearchPortDescriptorsCommandConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
   }
 
-  // Section: api_fill_to_wire
+  FlutterRustBridgeTaskConstMeta get kSearchPortDescriptorsCommandConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "search_port_descriptors_command",
+        argNames: [],
+      );
+
+  Future<void> openPortCommand(
+      {required int handle, required String serialPort, dynamic hint}) {
+    var arg0 = api2wire_u32(handle);
+    var arg1 = _platform.api2wire_String(serialPort);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_open_port_command(port_, arg0, arg1),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kOpenPortCommandConstMeta,
+      argValues: [handle, serialPort],
+      hint: hint,
+    ));
+  }
 
-}
+  FlutterRustBridgeTaskConstMeta get kOpenPortCommandConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "open_port_command",
+        argNames: ["handle", "serial_port"],
+      );
+
+  Future<void> openPortWithConfigCommand(
+      {required int handle,
+      required String serialPort,
+      required ConnectionConfig config,
+      dynamic hint}) {
+    var arg0 = api2wire_u32(handle);
+    var arg1 = _platform.api2wire_String(serialPort);
+    var arg2 = _platform.api2wire_box_autoadd_connection_config(config);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_open_port_with_config_command(
+              port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kOpenPortWithConfigCommandConstMeta,
+      argValues: [handle, serialPort, config],
+      hint: hint,
+    ));
+  }
 
-// Section: wire2api
-String _wire2api_String(dynamic raw) {
-  return raw as String;
-}
+  FlutterRustBridgeTaskConstMeta get kOpenPortWithConfigCommandConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "open_port_with_config_command",
+        argNames: ["handle", "serial_port", "config"],
+      );
+
+  Future<void> closePortCommand({required int handle, dynamic hint}) {
+    var arg0 = api2wire_u32(handle);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_close_port_command(port_, arg0),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kClosePortCommandConstMeta,
+      argValues: [handle],
+      hint: hint,
+    ));
+  }
 
-List<String> _wire2api_StringList(dynamic raw) {
-  return (raw as List<dynamic>).cast<String>();
-}
+  FlutterRustBridgeTaskConstMeta get kClosePortCommandConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "close_port_command",
+        argNames: ["handle"],
+      );
+
+  Future<void> setEventSoundPolicyCommand(
+      {required int handle, required EventSoundPolicy policy, dynamic hint}) {
+    var arg0 = api2wire_u32(handle);
+    var arg1 = _platform.api2wire_box_autoadd_event_sound_policy(policy);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_set_event_sound_policy_command(
+              port_, arg0, arg1),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kSetEventSoundPolicyCommandConstMeta,
+      argValues: [handle, policy],
+      hint: hint,
+    ));
+  }
 
-BasicSensor _wire2api_basic_sensor(dynamic raw) {
-  final arr = raw as List<dynamic>;
-  if (arr.length != 13)
-    throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
-  return BasicSensor(
-    valid: _wire2api_bool(arr[0]),
-    timeStamp: _wire2api_u32(arr[1]),
-    bumper: _wire2api_u32(arr[2]),
-    wheelDrop: _wire2api_u32(arr[3]),
-    cliff: _wire2api_u32(arr[4]),
-    leftEncoder: _wire2api_u32(arr[5]),
-    rightEncoder: _wire2api_u32(arr[6]),
-    leftPwm: _wire2api_u32(arr[7]),
-    rightPwm: _wire2api_u32(arr[8]),
-    button: _wire2api_u32(arr[9]),
-    charger: _wire2api_u32(arr[10]),
-    battery: _wire2api_u32(arr[11]),
-    overcurrentFlags: _wire2api_u32(arr[12]),
-  );
-}
+  FlutterRustBridgeTaskConstMeta get kSetEventSoundPolicyCommandConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "set_event_sound_policy_command",
+        argNames: ["handle", "policy"],
+      );
+
+  Future<void> setReconnectPolicyCommand(
+      {required int handle, required ReconnectPolicy policy, dynamic hint}) {
+    var arg0 = api2wire_u32(handle);
+    var arg1 = _platform.api2wire_box_autoadd_reconnect_policy(policy);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_set_reconnect_policy_command(port_, arg0, arg1),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kSetReconnectPolicyCommandConstMeta,
+      argValues: [handle, policy],
+      hint: hint,
+    ));
+  }
 
-bool _wire2api_bool(dynamic raw) {
-  return raw as bool;
-}
+  FlutterRustBridgeTaskConstMeta get kSetReconnectPolicyCommandConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "set_reconnect_policy_command",
+        argNames: ["handle", "policy"],
+      );
+
+  Future<String> startCaptureCommand(
+      {required int handle, required String path, dynamic hint}) {
+    var arg0 = api2wire_u32(handle);
+    var arg1 = _platform.api2wire_String(path);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_start_capture_command(port_, arg0, arg1),
+      parseSuccessData: _wire2api_String,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kStartCaptureCommandConstMeta,
+      argValues: [handle, path],
+      hint: hint,
+    ));
+  }
 
-Cliff _wire2api_cliff(dynamic raw) {
-  final arr = raw as List<dynamic>;
-  if (arr.length != 4)
-    throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-  return Cliff(
-    valid: _wire2api_bool(arr[0]),
-    rightCliffSensor: _wire2api_u32(arr[1]),
-    centralCliffSensor: _wire2api_u32(arr[2]),
-    leftCliffSensor: _wire2api_u32(arr[3]),
-  );
-}
+  FlutterRustBridgeTaskConstMeta get kStartCaptureCommandConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "start_capture_command",
+        argNames: ["handle", "path"],
+      );
+
+  Future<void> stopCaptureCommand({required int handle, dynamic hint}) {
+    var arg0 = api2wire_u32(handle);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_stop_capture_command(port_, arg0),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kStopCaptureCommandConstMeta,
+      argValues: [handle],
+      hint: hint,
+    ));
+  }
 
-ControllerInfo _wire2api_controller_info(dynamic raw) {
-  final arr = raw as List<dynamic>;
-  if (arr.length != 5)
-    throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-  return ControllerInfo(
-    valid: _wire2api_bool(arr[0]),
-    isUserConfigured: _wire2api_u32(arr[1]),
-    pGain: _wire2api_u32(arr[2]),
-    iGain: _wire2api_u32(arr[3]),
-    dGain: _wire2api_u32(arr[4]),
-  );
-}
+  FlutterRustBridgeTaskConstMeta get kStopCaptureCommandConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "stop_capture_command",
+        argNames: ["handle"],
+      );
+
+  Future<void> replayModeCommand(
+      {required int handle,
+      required ReplayMode mode,
+      required double speed,
+      dynamic hint}) {
+    var arg0 = api2wire_u32(handle);
+    var arg1 = api2wire_replay_mode(mode);
+    var arg2 = api2wire_f64(speed);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_replay_mode_command(port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kReplayModeCommandConstMeta,
+      argValues: [handle, mode, speed],
+      hint: hint,
+    ));
+  }
 
-Current _wire2api_current(dynamic raw) {
-  final arr = raw as List<dynamic>;
-  if (arr.length != 3)
-    throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-  return Current(
-    valid: _wire2api_bool(arr[0]),
-    leftMotor: _wire2api_u32(arr[1]),
-    rightMotor: _wire2api_u32(arr[2]),
-  );
-}
+  FlutterRustBridgeTaskConstMeta get kReplayModeCommandConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "replay_mode_command",
+        argNames: ["handle", "mode", "speed"],
+      );
+
+  Future<void> replayPauseCommand(
+      {required int handle, required bool paused, dynamic hint}) {
+    var arg0 = api2wire_u32(handle);
+    var arg1 = api2wire_bool(paused);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_replay_pause_command(port_, arg0, arg1),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kReplayPauseCommandConstMeta,
+      argValues: [handle, paused],
+      hint: hint,
+    ));
+  }
 
-DockingIR _wire2api_docking_ir(dynamic raw) {
-  final arr = raw as List<dynamic>;
-  if (arr.length != 4)
-    throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-  return DockingIR(
-    valid: _wire2api_bool(arr[0]),
-    rightSignal: _wire2api_u32(arr[1]),
-    centralSignal: _wire2api_u32(arr[2]),
-    leftSignal: _wire2api_u32(arr[3]),
-  );
-}
+  FlutterRustBridgeTaskConstMeta get kReplayPauseCommandConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "replay_pause_command",
+        argNames: ["handle", "paused"],
+      );
+
+  Future<void> replaySeekCommand(
+      {required int handle, required int positionMs, dynamic hint}) {
+    var arg0 = api2wire_u32(handle);
+    var arg1 = _platform.api2wire_u64(positionMs);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_replay_seek_command(port_, arg0, arg1),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kReplaySeekCommandConstMeta,
+      argValues: [handle, positionMs],
+      hint: hint,
+    ));
+  }
 
-Feedback _wire2api_feedback(dynamic raw) {
-  final arr = raw as List<dynamic>;
-  if (arr.length != 12)
-    throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
-  return Feedback(
-    epochTimeStamp: _wire2api_String(arr[0]),
-    basicSensor: _wire2api_basic_sensor(arr[1]),
-    dockingIr: _wire2api_docking_ir(arr[2]),
-    inertialSensor: _wire2api_inertial_sensor(arr[3]),
-    cliff: _wire2api_cliff(arr[4]),
-    current: _wire2api_current(arr[5]),
-    hardwareVersion: _wire2api_hardware_version(arr[6]),
-    firmwareVersion: _wire2api_firmware_version(arr[7]),
-    gyro: _wire2api_gyro(arr[8]),
-    generalPurposeInput: _wire2api_general_purpose_input(arr[9]),
-    uniqueDeviceId: _wire2api_unique_device_id(arr[10]),
-    controllerInfo: _wire2api_controller_info(arr[11]),
-  );
-}
+  FlutterRustBridgeTaskConstMeta get kReplaySeekCommandConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "replay_seek_command",
+        argNames: ["handle", "position_ms"],
+      );
+
+  Future<void> replayStepCommand({required int handle, dynamic hint}) {
+    var arg0 = api2wire_u32(handle);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_replay_step_command(port_, arg0),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kReplayStepCommandConstMeta,
+      argValues: [handle],
+      hint: hint,
+    ));
+  }
 
-FirmwareVersion _wire2api_firmware_version(dynamic raw) {
-  final arr = raw as List<dynamic>;
-  if (arr.length != 4)
-    throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-  return FirmwareVersion(
-    valid: _wire2api_bool(arr[0]),
-    patch: _wire2api_u32(arr[1]),
-    minor: _wire2api_u32(arr[2]),
-    major: _wire2api_u32(arr[3]),
-  );
-}
+  FlutterRustBridgeTaskConstMeta get kReplayStepCommandConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "replay_step_command",
+        argNames: ["handle"],
+      );
+
+  Future<void> driveCommand(
+      {required int handle,
+      required double linear,
+      required double angular,
+      dynamic hint}) {
+    var arg0 = api2wire_u32(handle);
+    var arg1 = api2wire_f64(linear);
+    var arg2 = api2wire_f64(angular);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_drive_command(port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kDriveCommandConstMeta,
+      argValues: [handle, linear, angular],
+      hint: hint,
+    ));
+  }
 
-GeneralPurposeInput _wire2api_general_purpose_input(dynamic raw) {
-  final arr = raw as List<dynamic>;
-  if (arr.length != 6)
-    throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-  return GeneralPurposeInput(
-    valid: _wire2api_bool(arr[0]),
-    dCh0: _wire2api_u32(arr[1]),
-    aCh0: _wire2api_u32(arr[2]),
-    aCh1: _wire2api_u32(arr[3]),
-    aCh2: _wire2api_u32(arr[4]),
-    aCh3: _wire2api_u32(arr[5]),
-  );
-}
+  FlutterRustBridgeTaskConstMeta get kDriveCommandConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "drive_command",
+        argNames: ["handle", "linear", "angular"],
+      );
 
-Gyro _wire2api_gyro(dynamic raw) {
-  final arr = raw as List<dynamic>;
-  if (arr.length != 12)
-    throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
-  return Gyro(
-    valid: _wire2api_bool(arr[0]),
-    frameId: _wire2api_u32(arr[1]),
-    followedDataLength: _wire2api_u32(arr[2]),
-    x0: _wire2api_u32(arr[3]),
-    y0: _wire2api_u32(arr[4]),
-    z0: _wire2api_u32(arr[5]),
-    x1: _wire2api_u32(arr[6]),
-    y1: _wire2api_u32(arr[7]),
-    z1: _wire2api_u32(arr[8]),
-    x2: _wire2api_u32(arr[9]),
-    y2: _wire2api_u32(arr[10]),
-    z2: _wire2api_u32(arr[11]),
-  );
-}
+  Future<void> baseControlCommand(
+      {required int handle,
+      required int speed,
+      required int radius,
+      dynamic hint}) {
+    var arg0 = api2wire_u32(handle);
+    var arg1 = api2wire_i16(speed);
+    var arg2 = api2wire_i16(radius);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_base_control_command(port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kBaseControlCommandConstMeta,
+      argValues: [handle, speed, radius],
+      hint: hint,
+    ));
+  }
 
-HardwareVersion _wire2api_hardware_version(dynamic raw) {
-  final arr = raw as List<dynamic>;
-  if (arr.length != 4)
-    throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-  return HardwareVersion(
-    valid: _wire2api_bool(arr[0]),
-    patch: _wire2api_u32(arr[1]),
-    minor: _wire2api_u32(arr[2]),
-    major: _wire2api_u32(arr[3]),
-  );
-}
+  FlutterRustBridgeTaskConstMeta get kBaseControlCommandConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "base_control_command",
+        argNames: ["handle", "speed", "radius"],
+      );
 
-InertialSensor _wire2api_inertial_sensor(dynamic raw) {
-  final arr = raw as List<dynamic>;
-  if (arr.length != 3)
-    throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-  return InertialSensor(
-    valid: _wire2api_bool(arr[0]),
-    angle: _wire2api_u32(arr[1]),
-    angleRate: _wire2api_u32(arr[2]),
-  );
-}
+  Future<void> soundCommand(
+      {required int handle,
+      required double frequency,
+      required int durationMs,
+      dynamic hint}) {
+    var arg0 = api2wire_u32(handle);
+    var arg1 = api2wire_f64(frequency);
+    var arg2 = api2wire_u8(durationMs);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_sound_command(port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kSoundCommandConstMeta,
+      argValues: [handle, frequency, durationMs],
+      hint: hint,
+    ));
+  }
 
-List<Feedback> _wire2api_list_feedback(dynamic raw) {
-  return (raw as List<dynamic>).map(_wire2api_feedback).toList();
-}
+  FlutterRustBridgeTaskConstMeta get kSoundCommandConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "sound_command",
+        argNames: ["handle", "frequency", "duration_ms"],
+      );
+
+  Future<void> noteCommand(
+      {required int handle,
+      required String note,
+      required int durationMs,
+      dynamic hint}) {
+    var arg0 = api2wire_u32(handle);
+    var arg1 = _platform.api2wire_String(note);
+    var arg2 = api2wire_u8(durationMs);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_note_command(port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kNoteCommandConstMeta,
+      argValues: [handle, note, durationMs],
+      hint: hint,
+    ));
+  }
 
-int _wire2api_u32(dynamic raw) {
-  return raw as int;
-}
+  FlutterRustBridgeTaskConstMeta get kNoteCommandConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "note_command",
+        argNames: ["handle", "note", "duration_ms"],
+      );
+
+  Future<void> playTuneCommand(
+      {required int handle,
+      required List<TuneNote> notes,
+      required double tempoBpm,
+      dynamic hint}) {
+    var arg0 = api2wire_u32(handle);
+    var arg1 = _platform.api2wire_list_tune_note(notes);
+    var arg2 = api2wire_f64(tempoBpm);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_play_tune_command(port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kPlayTuneCommandConstMeta,
+      argValues: [handle, notes, tempoBpm],
+      hint: hint,
+    ));
+  }
 
-int _wire2api_u8(dynamic raw) {
-  return raw as int;
-}
+  FlutterRustBridgeTaskConstMeta get kPlayTuneCommandConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "play_tune_command",
+        argNames: ["handle", "notes", "tempo_bpm"],
+      );
+
+  Future<void> playRtttlCommand(
+      {required int handle, required String rtttl, dynamic hint}) {
+    var arg0 = api2wire_u32(handle);
+    var arg1 = _platform.api2wire_String(rtttl);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_play_rtttl_command(port_, arg0, arg1),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kPlayRtttlCommandConstMeta,
+      argValues: [handle, rtttl],
+      hint: hint,
+    ));
+  }
 
-Uint8List _wire2api_uint_8_list(dynamic raw) {
-  return raw as Uint8List;
-}
+  FlutterRustBridgeTaskConstMeta get kPlayRtttlCommandConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "play_rtttl_command",
+        argNames: ["handle", "rtttl"],
+      );
+
+  Future<TuneProgress> tuneProgressCommand(
+      {required int handle, dynamic hint}) {
+    var arg0 = api2wire_u32(handle);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_tune_progress_command(port_, arg0),
+      parseSuccessData: _wire2api_tune_progress,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kTuneProgressCommandConstMeta,
+      argValues: [handle],
+      hint: hint,
+    ));
+  }
 
-UniqueDeviceId _wire2api_unique_device_id(dynamic raw) {
-  final arr = raw as List<dynamic>;
-  if (arr.length != 4)
-    throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-  return UniqueDeviceId(
-    valid: _wire2api_bool(arr[0]),
-    udid0: _wire2api_u32(arr[1]),
-    udid1: _wire2api_u32(arr[2]),
-    udid2: _wire2api_u32(arr[3]),
-  );
-}
+  FlutterRustBridgeTaskConstMeta get kTuneProgressCommandConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "tune_progress_command",
+        argNames: ["handle"],
+      );
+
+  Future<void> setTuneTempoCommand(
+      {required int handle, required double tempoBpm, dynamic hint}) {
+    var arg0 = api2wire_u32(handle);
+    var arg1 = api2wire_f64(tempoBpm);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_set_tune_tempo_command(port_, arg0, arg1),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kSetTuneTempoCommandConstMeta,
+      argValues: [handle, tempoBpm],
+      hint: hint,
+    ));
+  }
 
-void _wire2api_unit(dynamic raw) {
-  return;
-}
+  FlutterRustBridgeTaskConstMeta get kSetTuneTempoCommandConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "set_tune_tempo_command",
+        argNames: ["handle", "tempo_bpm"],
+      );
+
+  Future<void> stopTuneCommand({required int handle, dynamic hint}) {
+    var arg0 = api2wire_u32(handle);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_stop_tune_command(port_, arg0),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kStopTuneCommandConstMeta,
+      argValues: [handle],
+      hint: hint,
+    ));
+  }
 
-// ignore_for_file: camel_case_types, non_constant_identifier_names, avoid_positional_boolean_parameters, annotate_overrides, constant_identifier_names
+  FlutterRustBridgeTaskConstMeta get kStopTuneCommandConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "stop_tune_command",
+        argNames: ["handle"],
+      );
+
+  Future<void> soundSequenceCommand(
+      {required int handle, required SoundSequence seq, dynamic hint}) {
+    var arg0 = api2wire_u32(handle);
+    var arg1 = api2wire_sound_sequence(seq);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_sound_sequence_command(port_, arg0, arg1),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kSoundSequenceCommandConstMeta,
+      argValues: [handle, seq],
+      hint: hint,
+    ));
+  }
 
-// AUTO GENERATED FILE, DO NOT EDIT.
-//
-// Generated by `package:ffigen`.
-
-/// generated by flutter_rust_bridge
-class Turtlebot2Wire implements FlutterRustBridgeWireBase {
-  /// Holds the symbol lookup function.
-  final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
-      _lookup;
-
-  /// The symbols are looked up in [dynamicLibrary].
-  Turtlebot2Wire(ffi.DynamicLibrary dynamicLibrary)
-      : _lookup = dynamicLibrary.lookup;
-
-  /// The symbols are looked up with [lookup].
-  Turtlebot2Wire.fromLookup(
-      ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
-          lookup)
-      : _lookup = lookup;
-
-  void wire_spawn_turtlebot(
-    int port_,
-  ) {
-    return _wire_spawn_turtlebot(
-      port_,
-    );
+  FlutterRustBridgeTaskConstMeta get kSoundSequenceCommandConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "sound_sequence_command",
+        argNames: ["handle", "seq"],
+      );
+
+  Future<void> requestExtraCommand(
+      {required int handle,
+      required bool hwVer,
+      required bool fwVer,
+      required bool udid,
+      dynamic hint}) {
+    var arg0 = api2wire_u32(handle);
+    var arg1 = api2wire_bool(hwVer);
+    var arg2 = api2wire_bool(fwVer);
+    var arg3 = api2wire_bool(udid);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_request_extra_command(
+              port_, arg0, arg1, arg2, arg3),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRequestExtraCommandConstMeta,
+      argValues: [handle, hwVer, fwVer, udid],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRequestExtraCommandConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "request_extra_command",
+        argNames: ["handle", "hw_ver", "fw_ver", "udid"],
+      );
+
+  Future<void> generalPurposeOutputCommand(
+      {required int handle,
+      required bool dOutCh0,
+      required bool dOutCh1,
+      required bool dOutCh2,
+      required bool dOutCh3,
+      required bool power3V3,
+      required bool power5V0,
+      required bool power12V5A,
+      required bool power12V1A5,
+      required bool redLed1,
+      required bool redLed2,
+      required bool greenLed1,
+      required bool greenLed2,
+      dynamic hint}) {
+    var arg0 = api2wire_u32(handle);
+    var arg1 = api2wire_bool(dOutCh0);
+    var arg2 = api2wire_bool(dOutCh1);
+    var arg3 = api2wire_bool(dOutCh2);
+    var arg4 = api2wire_bool(dOutCh3);
+    var arg5 = api2wire_bool(power3V3);
+    var arg6 = api2wire_bool(power5V0);
+    var arg7 = api2wire_bool(power12V5A);
+    var arg8 = api2wire_bool(power12V1A5);
+    var arg9 = api2wire_bool(redLed1);
+    var arg10 = api2wire_bool(redLed2);
+    var arg11 = api2wire_bool(greenLed1);
+    var arg12 = api2wire_bool(greenLed2);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_general_purpose_output_command(
+              port_,
+              arg0,
+              arg1,
+              arg2,
+              arg3,
+              arg4,
+              arg5,
+              arg6,
+              arg7,
+              arg8,
+              arg9,
+              arg10,
+              arg11,
+              arg12),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kGeneralPurposeOutputCommandConstMeta,
+      argValues: [
+          handle,
+          dOutCh0,
+          dOutCh1,
+          dOutCh2,
+          dOutCh3,
+          power3V3,
+          power5V0,
+          power12V5A,
+          power12V1A5,
+          redLed1,
+          redLed2,
+          greenLed1,
+          greenLed2],
+      hint: hint,
+    ));
   }
 
-  late final _wire_spawn_turtlebotPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
-          'wire_spawn_turtlebot');
-  late final _wire_spawn_turtlebot =
-      _wire_spawn_turtlebotPtr.asFunction<void Function(int)>();
+  FlutterRustBridgeTaskConstMeta get kGeneralPurposeOutputCommandConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "general_purpose_output_command",
+        argNames: [
+            "handle",
+            "d_out_ch0",
+            "d_out_ch1",
+            "d_out_ch2",
+            "d_out_ch3",
+            "power_3v3",
+            "power_5v0",
+            "power_12v5a",
+            "power_12v1a5",
+            "red_led1",
+            "red_led2",
+            "green_led1",
+            "green_led2"],
+      );
 
-  void wire_receive_from_turtlebot(
-    int port_,
-  ) {
-    return _wire_receive_from_turtlebot(
-      port_,
+  Future<void> setControllerGainCommand(
+      {required int handle,
+      required bool isUserConfigured,
+      required int p,
+      required double i,
+      required int d,
+      dynamic hint}) {
+    var arg0 = api2wire_u32(handle);
+    var arg1 = api2wire_bool(isUserConfigured);
+    var arg2 = api2wire_u32(p);
+    var arg3 = api2wire_f32(i);
+    var arg4 = api2wire_u32(d);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_set_controller_gain_command(
+              port_, arg0, arg1, arg2, arg3, arg4),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kSetControllerGainCommandConstMeta,
+      argValues: [handle, isUserConfigured, p, i, d],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kSetControllerGainCommandConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "set_controller_gain_command",
+        argNames: ["handle", "is_user_configured", "p", "i", "d"],
+      );
+
+  Future<void> getControllerGain({required int handle, dynamic hint}) {
+    var arg0 = api2wire_u32(handle);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_get_controller_gain(port_, arg0),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kGetControllerGainConstMeta,
+      argValues: [handle],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kGetControllerGainConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "get_controller_gain",
+        argNames: ["handle"],
+      );
+
+  void dispose() {
+    _platform.dispose();
+  }
+// Section: wire2api
+
+  BasicSensor _wire2api_basic_sensor(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 20)
+      throw Exception('unexpected arr length: expect 20 but see ${arr.length}');
+    return BasicSensor(
+      valid: _wire2api_bool(arr[0]),
+      timeStamp: _wire2api_u32(arr[1]),
+      bumper: _wire2api_u32(arr[2]),
+      wheelDrop: _wire2api_u32(arr[3]),
+      cliff: _wire2api_u32(arr[4]),
+      leftEncoder: _wire2api_u32(arr[5]),
+      rightEncoder: _wire2api_u32(arr[6]),
+      leftPwm: _wire2api_u32(arr[7]),
+      rightPwm: _wire2api_u32(arr[8]),
+      button: _wire2api_u32(arr[9]),
+      charger: _wire2api_u32(arr[10]),
+      battery: _wire2api_u8(arr[11]),
+      batteryVolts: _wire2api_f64(arr[12]),
+      overcurrentFlags: _wire2api_u32(arr[13]),
+      bumpers: _wire2api_bumper_flags(arr[14]),
+      wheelDrops: _wire2api_wheel_drop_flags(arr[15]),
+      cliffs: _wire2api_cliff_flags(arr[16]),
+      buttons: _wire2api_button_flags(arr[17]),
+      chargerState: _wire2api_charger_state(arr[18]),
+      overcurrents: _wire2api_overcurrent_flags(arr[19]),
     );
   }
 
-  late final _wire_receive_from_turtlebotPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
-          'wire_receive_from_turtlebot');
-  late final _wire_receive_from_turtlebot =
-      _wire_receive_from_turtlebotPtr.asFunction<void Function(int)>();
+  BatteryState _wire2api_battery_state(dynamic raw) {
+    return BatteryState.values[raw as int];
+  }
 
-  void wire_search_port_command(
-    int port_,
-  ) {
-    return _wire_search_port_command(
-      port_,
+  BatteryStatus _wire2api_battery_status(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return BatteryStatus(
+      valid: _wire2api_bool(arr[0]),
+      volts: _wire2api_f64(arr[1]),
+      smoothedVolts: _wire2api_f64(arr[2]),
+      cells: _wire2api_u32(arr[3]),
+      percentage: _wire2api_f64(arr[4]),
+      state: _wire2api_battery_state(arr[5]),
+      timeRemainingSec: _wire2api_f64(arr[6]),
     );
   }
 
-  late final _wire_search_port_commandPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
-          'wire_search_port_command');
-  late final _wire_search_port_command =
-      _wire_search_port_commandPtr.asFunction<void Function(int)>();
+  bool _wire2api_bool(dynamic raw) {
+    return raw as bool;
+  }
+
+  BumperFlags _wire2api_bumper_flags(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return BumperFlags(
+      left: _wire2api_bool(arr[0]),
+      center: _wire2api_bool(arr[1]),
+      right: _wire2api_bool(arr[2]),
+    );
+  }
 
-  void wire_open_port_command(
-    int port_,
-    ffi.Pointer<wire_uint_8_list> serial_port,
-  ) {
-    return _wire_open_port_command(
-      port_,
-      serial_port,
+  ButtonFlags _wire2api_button_flags(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ButtonFlags(
+      b0: _wire2api_bool(arr[0]),
+      b1: _wire2api_bool(arr[1]),
+      b2: _wire2api_bool(arr[2]),
     );
   }
 
-  late final _wire_open_port_commandPtr = _lookup<
-      ffi.NativeFunction<
-          ffi.Void Function(ffi.Int64,
-              ffi.Pointer<wire_uint_8_list>)>>('wire_open_port_command');
-  late final _wire_open_port_command = _wire_open_port_commandPtr
-      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+  ChargerState _wire2api_charger_state(dynamic raw) {
+    return ChargerState.values[raw as int];
+  }
 
-  void wire_close_port_command(
-    int port_,
-  ) {
-    return _wire_close_port_command(
-      port_,
+  Cliff _wire2api_cliff(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return Cliff(
+      valid: _wire2api_bool(arr[0]),
+      rightCliffSensor: _wire2api_u32(arr[1]),
+      centralCliffSensor: _wire2api_u32(arr[2]),
+      leftCliffSensor: _wire2api_u32(arr[3]),
     );
   }
 
-  late final _wire_close_port_commandPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
-          'wire_close_port_command');
-  late final _wire_close_port_command =
-      _wire_close_port_commandPtr.asFunction<void Function(int)>();
-
-  void wire_base_control_command(
-    int port_,
-    int speed,
-    int radius,
-  ) {
-    return _wire_base_control_command(
-      port_,
-      speed,
-      radius,
+  CliffFlags _wire2api_cliff_flags(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return CliffFlags(
+      left: _wire2api_bool(arr[0]),
+      center: _wire2api_bool(arr[1]),
+      right: _wire2api_bool(arr[2]),
     );
   }
 
-  late final _wire_base_control_commandPtr = _lookup<
-      ffi.NativeFunction<
-          ffi.Void Function(
-              ffi.Int64, ffi.Uint16, ffi.Uint16)>>('wire_base_control_command');
-  late final _wire_base_control_command =
-      _wire_base_control_commandPtr.asFunction<void Function(int, int, int)>();
-
-  void wire_sound_command(
-    int port_,
-    int freq,
-    int amp,
-    int duration,
-  ) {
-    return _wire_sound_command(
-      port_,
-      freq,
-      amp,
-      duration,
+  ControllerInfo _wire2api_controller_info(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ControllerInfo(
+      valid: _wire2api_bool(arr[0]),
+      isUserConfigured: _wire2api_u32(arr[1]),
+      pGain: _wire2api_u32(arr[2]),
+      iGain: _wire2api_u32(arr[3]),
+      dGain: _wire2api_u32(arr[4]),
     );
   }
 
-  late final _wire_sound_commandPtr = _lookup<
-      ffi.NativeFunction<
-          ffi.Void Function(ffi.Int64, ffi.Uint8, ffi.Uint8,
-              ffi.Uint8)>>('wire_sound_command');
-  late final _wire_sound_command =
-      _wire_sound_commandPtr.asFunction<void Function(int, int, int, int)>();
-
-  void wire_sound_sequence_command(
-    int port_,
-    int seq,
-  ) {
-    return _wire_sound_sequence_command(
-      port_,
-      seq,
+  Current _wire2api_current(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return Current(
+      valid: _wire2api_bool(arr[0]),
+      leftMotor: _wire2api_u8(arr[1]),
+      rightMotor: _wire2api_u8(arr[2]),
+      leftMotorAmps: _wire2api_f64(arr[3]),
+      rightMotorAmps: _wire2api_f64(arr[4]),
     );
   }
 
-  late final _wire_sound_sequence_commandPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint8)>>(
-          'wire_sound_sequence_command');
-  late final _wire_sound_sequence_command =
-      _wire_sound_sequence_commandPtr.asFunction<void Function(int, int)>();
-
-  void wire_request_extra_command(
-    int port_,
-    bool hw_ver,
-    bool fw_ver,
-    bool udid,
-  ) {
-    return _wire_request_extra_command(
-      port_,
-      hw_ver ? 1 : 0,
-      fw_ver ? 1 : 0,
-      udid ? 1 : 0,
+  DecoderStats _wire2api_decoder_stats(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return DecoderStats(
+      framesOk: _wire2api_u64(arr[0]),
+      crcFailures: _wire2api_u64(arr[1]),
+      resyncs: _wire2api_u64(arr[2]),
+      bytesDiscarded: _wire2api_u64(arr[3]),
+      unknownIds: _wire2api_u64(arr[4]),
+      truncatedSubPayloads: _wire2api_u64(arr[5]),
+      lastError: _wire2api_String(arr[6]),
     );
   }
 
-  late final _wire_request_extra_commandPtr = _lookup<
-      ffi.NativeFunction<
-          ffi.Void Function(ffi.Int64, ffi.Uint8, ffi.Uint8,
-              ffi.Uint8)>>('wire_request_extra_command');
-  late final _wire_request_extra_command = _wire_request_extra_commandPtr
-      .asFunction<void Function(int, int, int, int)>();
-
-  void wire_general_purpose_output_command(
-    int port_,
-    bool d_out_ch0,
-    bool d_out_ch1,
-    bool d_out_ch2,
-    bool d_out_ch3,
-    bool power_3v3,
-    bool power_5v0,
-    bool power_12v5a,
-    bool power_12v1a5,
-    bool red_led1,
-    bool red_led2,
-    bool green_led1,
-    bool green_led2,
-  ) {
-    return _wire_general_purpose_output_command(
-      port_,
-      d_out_ch0 ? 1 : 0,
-      d_out_ch1 ? 1 : 0,
-      d_out_ch2 ? 1 : 0,
-      d_out_ch3 ? 1 : 0,
-      power_3v3 ? 1 : 0,
-      power_5v0 ? 1 : 0,
-      power_12v5a ? 1 : 0,
-      power_12v1a5 ? 1 : 0,
-      red_led1 ? 1 : 0,
-      red_led2 ? 1 : 0,
-      green_led1 ? 1 : 0,
-      green_led2 ? 1 : 0,
+  DeviceEvent _wire2api_device_event(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return DeviceEvent(
+      attached: _wire2api_bool(arr[0]),
+      port: _wire2api_port_descriptor(arr[1]),
     );
   }
 
-  late final _wire_general_purpose_output_commandPtr = _lookup<
-      ffi.NativeFunction<
-          ffi.Void Function(
-              ffi.Int64,
-              ffi.Uint8,
-              ffi.Uint8,
-              ffi.Uint8,
-              ffi.Uint8,
-              ffi.Uint8,
-              ffi.Uint8,
-              ffi.Uint8,
-              ffi.Uint8,
-              ffi.Uint8,
-              ffi.Uint8,
-              ffi.Uint8,
-              ffi.Uint8)>>('wire_general_purpose_output_command');
-  late final _wire_general_purpose_output_command =
-      _wire_general_purpose_output_commandPtr.asFunction<
-          void Function(int, int, int, int, int, int, int, int, int, int, int,
-              int, int)>();
-
-  void wire_set_controller_gain_command(
-    int port_,
-    bool is_user_configured,
-    int p,
-    double i,
-    int d,
-  ) {
-    return _wire_set_controller_gain_command(
-      port_,
-      is_user_configured ? 1 : 0,
-      p,
-      i,
-      d,
+  DockingIR _wire2api_docking_ir(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return DockingIR(
+      valid: _wire2api_bool(arr[0]),
+      rightSignal: _wire2api_u32(arr[1]),
+      centralSignal: _wire2api_u32(arr[2]),
+      leftSignal: _wire2api_u32(arr[3]),
     );
   }
 
-  late final _wire_set_controller_gain_commandPtr = _lookup<
-      ffi.NativeFunction<
-          ffi.Void Function(ffi.Int64, ffi.Uint8, ffi.Uint32, ffi.Float,
-              ffi.Uint32)>>('wire_set_controller_gain_command');
-  late final _wire_set_controller_gain_command =
-      _wire_set_controller_gain_commandPtr
-          .asFunction<void Function(int, int, int, double, int)>();
+  EncoderTicks _wire2api_encoder_ticks(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return EncoderTicks(
+      valid: _wire2api_bool(arr[0]),
+      left: _wire2api_i64(arr[1]),
+      right: _wire2api_i64(arr[2]),
+      leftDelta: _wire2api_i64(arr[3]),
+      rightDelta: _wire2api_i64(arr[4]),
+      timeStampMs: _wire2api_u64(arr[5]),
+      dtMs: _wire2api_u64(arr[6]),
+    );
+  }
 
-  void wire_get_controller_gain(
-    int port_,
-  ) {
-    return _wire_get_controller_gain(
-      port_,
+  double _wire2api_f64(dynamic raw) {
+    return raw as double;
+  }
+
+  Feedback _wire2api_feedback(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 15)
+      throw Exception('unexpected arr length: expect 15 but see ${arr.length}');
+    return Feedback(
+      epochTimeStamp: _wire2api_String(arr[0]),
+      basicSensor: _wire2api_basic_sensor(arr[1]),
+      dockingIr: _wire2api_docking_ir(arr[2]),
+      inertialSensor: _wire2api_inertial_sensor(arr[3]),
+      cliff: _wire2api_cliff(arr[4]),
+      current: _wire2api_current(arr[5]),
+      hardwareVersion: _wire2api_hardware_version(arr[6]),
+      firmwareVersion: _wire2api_firmware_version(arr[7]),
+      gyro: _wire2api_gyro(arr[8]),
+      generalPurposeInput: _wire2api_general_purpose_input(arr[9]),
+      uniqueDeviceId: _wire2api_unique_device_id(arr[10]),
+      controllerInfo: _wire2api_controller_info(arr[11]),
+      battery: _wire2api_battery_status(arr[12]),
+      encoders: _wire2api_encoder_ticks(arr[13]),
+      unknownSubPayloads: _wire2api_list_unknown_sub_payload(arr[14]),
+    );
+  }
+
+  FirmwareVersion _wire2api_firmware_version(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return FirmwareVersion(
+      valid: _wire2api_bool(arr[0]),
+      patch: _wire2api_u32(arr[1]),
+      minor: _wire2api_u32(arr[2]),
+      major: _wire2api_u32(arr[3]),
     );
   }
 
-  late final _wire_get_controller_gainPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
-          'wire_get_controller_gain');
-  late final _wire_get_controller_gain =
-      _wire_get_controller_gainPtr.asFunction<void Function(int)>();
+  FrbAnyhowException _wire2api_FrbAnyhowException(dynamic raw) {
+    return FrbAnyhowException(raw as String);
+  }
 
-  ffi.Pointer<wire_uint_8_list> new_uint_8_list(
-    int len,
-  ) {
-    return _new_uint_8_list(
-      len,
+  GeneralPurposeInput _wire2api_general_purpose_input(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return GeneralPurposeInput(
+      valid: _wire2api_bool(arr[0]),
+      dCh0: _wire2api_u32(arr[1]),
+      aCh0: _wire2api_u32(arr[2]),
+      aCh1: _wire2api_u32(arr[3]),
+      aCh2: _wire2api_u32(arr[4]),
+      aCh3: _wire2api_u32(arr[5]),
     );
   }
 
-  late final _new_uint_8_listPtr = _lookup<
-      ffi.NativeFunction<
-          ffi.Pointer<wire_uint_8_list> Function(
-              ffi.Int32)>>('new_uint_8_list');
-  late final _new_uint_8_list = _new_uint_8_listPtr
-      .asFunction<ffi.Pointer<wire_uint_8_list> Function(int)>();
+  Gyro _wire2api_gyro(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return Gyro(
+      valid: _wire2api_bool(arr[0]),
+      frameId: _wire2api_u32(arr[1]),
+      followedDataLength: _wire2api_u32(arr[2]),
+      samples: _wire2api_list_gyro_sample(arr[3]),
+      xRadPerSec: _wire2api_f64(arr[4]),
+      yRadPerSec: _wire2api_f64(arr[5]),
+      zRadPerSec: _wire2api_f64(arr[6]),
+    );
+  }
+
+  GyroSample _wire2api_gyro_sample(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return GyroSample(
+      frameId: _wire2api_u32(arr[0]),
+      x: _wire2api_i16(arr[1]),
+      y: _wire2api_i16(arr[2]),
+      z: _wire2api_i16(arr[3]),
+      xRadPerSec: _wire2api_f64(arr[4]),
+      yRadPerSec: _wire2api_f64(arr[5]),
+      zRadPerSec: _wire2api_f64(arr[6]),
+    );
+  }
 
-  void free_WireSyncReturnStruct(
-    WireSyncReturnStruct val,
-  ) {
-    return _free_WireSyncReturnStruct(
-      val,
+  HardwareVersion _wire2api_hardware_version(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return HardwareVersion(
+      valid: _wire2api_bool(arr[0]),
+      patch: _wire2api_u32(arr[1]),
+      minor: _wire2api_u32(arr[2]),
+      major: _wire2api_u32(arr[3]),
     );
   }
 
-  late final _free_WireSyncReturnStructPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(WireSyncReturnStruct)>>(
-          'free_WireSyncReturnStruct');
-  late final _free_WireSyncReturnStruct = _free_WireSyncReturnStructPtr
-      .asFunction<void Function(WireSyncReturnStruct)>();
+  int _wire2api_i16(dynamic raw) {
+    return raw as int;
+  }
 
-  void store_dart_post_cobject(
-    DartPostCObjectFnType ptr,
-  ) {
-    return _store_dart_post_cobject(
-      ptr,
+  int _wire2api_i64(dynamic raw) {
+    return castInt(raw);
+  }
+
+  InertialSensor _wire2api_inertial_sensor(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return InertialSensor(
+      valid: _wire2api_bool(arr[0]),
+      angle: _wire2api_i16(arr[1]),
+      angleRate: _wire2api_i16(arr[2]),
+      angleDeg: _wire2api_f64(arr[3]),
+      angleRad: _wire2api_f64(arr[4]),
+      angleRateDegPerSec: _wire2api_f64(arr[5]),
+      angleRateRadPerSec: _wire2api_f64(arr[6]),
     );
   }
 
-  late final _store_dart_post_cobjectPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(DartPostCObjectFnType)>>(
-          'store_dart_post_cobject');
-  late final _store_dart_post_cobject = _store_dart_post_cobjectPtr
-      .asFunction<void Function(DartPostCObjectFnType)>();
-}
+  LatencyStats _wire2api_latency_stats(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return LatencyStats(
+      samples: _wire2api_u64(arr[0]),
+      lastMs: _wire2api_f64(arr[1]),
+      meanMs: _wire2api_f64(arr[2]),
+      maxMs: _wire2api_f64(arr[3]),
+    );
+  }
 
-class wire_uint_8_list extends ffi.Struct {
-  external ffi.Pointer<ffi.Uint8> ptr;
+  List<DeviceEvent> _wire2api_list_device_event(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_device_event).toList();
+  }
 
-  @ffi.Int32()
-  external int len;
-}
+  List<Feedback> _wire2api_list_feedback(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_feedback).toList();
+  }
 
-typedef DartPostCObjectFnType = ffi.Pointer<
-    ffi.NativeFunction<ffi.Uint8 Function(DartPort, ffi.Pointer<ffi.Void>)>>;
-typedef DartPort = ffi.Int64;
+  List<GyroSample> _wire2api_list_gyro_sample(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_gyro_sample).toList();
+  }
 
-const int CMD_LEN_BASE_CONTROL = 7;
+  List<PortDescriptor> _wire2api_list_port_descriptor(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_port_descriptor).toList();
+  }
 
-const int CMD_LEN_SOUND = 6;
+  List<UnknownSubPayload> _wire2api_list_unknown_sub_payload(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_unknown_sub_payload).toList();
+  }
 
-const int CMD_LEN_SOUND_SEQUENCE = 4;
+  MatchConfidence _wire2api_match_confidence(dynamic raw) {
+    return MatchConfidence.values[raw as int];
+  }
 
-const int CMD_LEN_REQUEST_EXTRA = 5;
+  OvercurrentFlags _wire2api_overcurrent_flags(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return OvercurrentFlags(
+      left: _wire2api_bool(arr[0]),
+      right: _wire2api_bool(arr[1]),
+    );
+  }
 
-const int CMD_LEN_GENERAL_PURPOSE_OUTPUT = 5;
+  PortDescriptor _wire2api_port_descriptor(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return PortDescriptor(
+      portName: _wire2api_String(arr[0]),
+      vid: _wire2api_u16(arr[1]),
+      pid: _wire2api_u16(arr[2]),
+      manufacturer: _wire2api_String(arr[3]),
+      product: _wire2api_String(arr[4]),
+      serialNumber: _wire2api_String(arr[5]),
+      confidence: _wire2api_match_confidence(arr[6]),
+    );
+  }
 
-const int CMD_LEN_SET_CONTROLLER_GAIN = 16;
+  String _wire2api_String(dynamic raw) {
+    return raw as String;
+  }
 
-const int CMD_LEN_GET_CONTROLLER_GAIN = 4;
+  List<String> _wire2api_StringList(dynamic raw) {
+    return (raw as List<dynamic>).cast<String>();
+  }
 
-const int CMD_SIZE_BASE_CONTROL = 4;
+  TuneProgress _wire2api_tune_progress(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return TuneProgress(
+      playing: _wire2api_bool(arr[0]),
+      name: _wire2api_String(arr[1]),
+      step: _wire2api_u32(arr[2]),
+      steps: _wire2api_u32(arr[3]),
+      elapsedMs: _wire2api_f64(arr[4]),
+      durationMs: _wire2api_f64(arr[5]),
+    );
+  }
 
-const int CMD_SIZE_SOUND = 3;
+  int _wire2api_u16(dynamic raw) {
+    return raw as int;
+  }
 
-const int CMD_SIZE_SOUND_SEQUENCE = 1;
+  int _wire2api_u32(dynamic raw) {
+    return raw as int;
+  }
 
-const int CMD_SIZE_REQUEST_EXTRA = 2;
+  int _wire2api_u64(dynamic raw) {
+    return castInt(raw);
+  }
 
-const int CMD_SIZE_GENERAL_PURPOSE_OUTPUT = 2;
+  int _wire2api_u8(dynamic raw) {
+    return raw as int;
+  }
 
-const int CMD_SIZE_SET_CONTROLLER_GAIN = 13;
+  Uint8List _wire2api_uint_8_list(dynamic raw) {
+    return raw as Uint8List;
+  }
 
-const int CMD_SIZE_GET_CONTROLLER_GAIN = 1;
+  UniqueDeviceId _wire2api_unique_device_id(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return UniqueDeviceId(
+      valid: _wire2api_bool(arr[0]),
+      udid0: _wire2api_u32(arr[1]),
+      udid1: _wire2api_u32(arr[2]),
+      udid2: _wire2api_u32(arr[3]),
+    );
+  }
 
-const int FDB_SIZE_BASIC_SENSOR_DATA = 15;
+  void _wire2api_unit(dynamic raw) {
+    return;
+  }
 
-const int FDB_SIZE_DOCKING_IR = 3;
+  UnknownSubPayload _wire2api_unknown_sub_payload(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return UnknownSubPayload(
+      id: _wire2api_u8(arr[0]),
+      data: _wire2api_uint_8_list(arr[1]),
+    );
+  }
+
+  WheelDropFlags _wire2api_wheel_drop_flags(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return WheelDropFlags(
+      left: _wire2api_bool(arr[0]),
+      right: _wire2api_bool(arr[1]),
+    );
+  }
+
+}
+
+// Section: api2wire
+
+@protected
+bool api2wire_bool(bool raw) {
+  return raw;
+}
+
+@protected
+double api2wire_f32(double raw) {
+  return raw;
+}
 
-const int FDB_SIZE_INERTIAL_SENSOR = 7;
+@protected
+double api2wire_f64(double raw) {
+  return raw;
+}
 
-const int FDB_SIZE_CLIFF = 6;
+@protected
+int api2wire_flow_control(FlowControl raw) {
+  return api2wire_i32(raw.index);
+}
 
-const int FDB_SIZE_CURRENT = 2;
+@protected
+int api2wire_i16(int raw) {
+  return raw;
+}
 
-const int FDB_SIZE_HARDWARE_VERSION = 4;
+@protected
+int api2wire_i32(int raw) {
+  return raw;
+}
 
-const int FDB_SIZE_FIRMWARE_VERSION = 4;
+@protected
+int api2wire_read_strategy(ReadStrategy raw) {
+  return api2wire_i32(raw.index);
+}
 
-const int FDB_SIZE_RAW_DATA_3_AXIS_GYRO_A = 14;
+@protected
+int api2wire_replay_mode(ReplayMode raw) {
+  return api2wire_i32(raw.index);
+}
 
-const int FDB_SIZE_RAW_DATA_3_AXIS_GYRO_B = 20;
+@protected
+int api2wire_sound_sequence(SoundSequence raw) {
+  return api2wire_i32(raw.index);
+}
 
-const int FDB_SIZE_GENERAL_PURPOSE_OUTPUT = 16;
+@protected
+int api2wire_u32(int raw) {
+  return raw;
+}
 
-const int FDB_SIZE_UNIQUE_DEVICE_IDENTIFIER = 12;
+@protected
+int api2wire_u8(int raw) {
+  return raw;
+}
 
-const int FDB_SIZE_CONTROLLER_INFO = 13;
+// Section: finalizer
@@ -0,0 +1,1118 @@
+// AUTO GENERATED FILE, DO NOT EDIT.
+// Generated by `flutter_rust_bridge`@ 1.82.6.
+// ignore_for_file: non_constant_identifier_names, unused_element, duplicate_ignore, directives_ordering, curly_braces_in_flow_control_structures, unnecessary_lambdas, slash_for_doc_comments, prefer_const_literals_to_create_immutables, implicit_dynamic_list_literal, duplicate_import, unused_import, unnecessary_import, prefer_single_quotes, prefer_const_constructors, use_super_parameters, always_use_package_imports, annotate_overrides, invalid_use_of_protected_member, constant_identifier_names, invalid_use_of_internal_member, prefer_is_empty, unnecessary_const
+
+import 'dart:convert';
+import 'dart:async';
+import 'dart:typed_data';
+import 'package:meta/meta.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge.dart';
+import 'bridge_generated.dart';
+export 'bridge_generated.dart';
+import 'dart:ffi' as ffi;
+
+class Turtlebot2Platform extends FlutterRustBridgeBase<Turtlebot2Wire> {
+  Turtlebot2Platform(ffi.DynamicLibrary dylib) : super(Turtlebot2Wire(dylib));
+
+// Section: api2wire
+
+  @protected
+  ffi.Pointer<wire_ConnectionConfig> api2wire_box_autoadd_connection_config(
+      ConnectionConfig raw) {
+    final ptr = inner.new_box_autoadd_connection_config_0();
+    _api_fill_to_wire_connection_config(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_EventSoundPolicy> api2wire_box_autoadd_event_sound_policy(
+      EventSoundPolicy raw) {
+    final ptr = inner.new_box_autoadd_event_sound_policy_0();
+    _api_fill_to_wire_event_sound_policy(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_ReconnectPolicy> api2wire_box_autoadd_reconnect_policy(
+      ReconnectPolicy raw) {
+    final ptr = inner.new_box_autoadd_reconnect_policy_0();
+    _api_fill_to_wire_reconnect_policy(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_list_tune_note> api2wire_list_tune_note(List<TuneNote> raw) {
+    final ans = inner.new_list_tune_note_0(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      _api_fill_to_wire_tune_note(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_uint_8_list> api2wire_String(String raw) {
+    return api2wire_uint_8_list(utf8.encoder.convert(raw));
+  }
+
+  @protected
+  int api2wire_u64(int raw) {
+    return raw;
+  }
+
+  @protected
+  ffi.Pointer<wire_uint_8_list> api2wire_uint_8_list(Uint8List raw) {
+    final ans = inner.new_uint_8_list_0(raw.length);
+    ans.ref.ptr.asTypedList(raw.length).setAll(0, raw);
+    return ans;
+  }
+
+// Section: finalizer
+
+// Section: api_fill_to_wire
+
+  void _api_fill_to_wire_box_autoadd_connection_config(
+      ConnectionConfig apiObj, ffi.Pointer<wire_ConnectionConfig> wireObj) {
+    _api_fill_to_wire_connection_config(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_event_sound_policy(
+      EventSoundPolicy apiObj, ffi.Pointer<wire_EventSoundPolicy> wireObj) {
+    _api_fill_to_wire_event_sound_policy(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_reconnect_policy(
+      ReconnectPolicy apiObj, ffi.Pointer<wire_ReconnectPolicy> wireObj) {
+    _api_fill_to_wire_reconnect_policy(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_connection_config(
+      ConnectionConfig apiObj, wire_ConnectionConfig wireObj) {
+    wireObj.baud_rate = api2wire_u32(apiObj.baudRate);
+    wireObj.read_timeout_ms = api2wire_u32(apiObj.readTimeoutMs);
+    wireObj.read_strategy = api2wire_read_strategy(apiObj.readStrategy);
+    wireObj.poll_interval_ms = api2wire_u32(apiObj.pollIntervalMs);
+    wireObj.buffer_size = api2wire_u32(apiObj.bufferSize);
+    wireObj.flow_control = api2wire_flow_control(apiObj.flowControl);
+  }
+
+  void _api_fill_to_wire_event_sound_policy(
+      EventSoundPolicy apiObj, wire_EventSoundPolicy wireObj) {
+    wireObj.enabled = api2wire_bool(apiObj.enabled);
+    wireObj.on_connected = api2wire_bool(apiObj.onConnected);
+    wireObj.connected = api2wire_sound_sequence(apiObj.connected);
+    wireObj.on_bump = api2wire_bool(apiObj.onBump);
+    wireObj.bump = api2wire_sound_sequence(apiObj.bump);
+    wireObj.on_low_battery = api2wire_bool(apiObj.onLowBattery);
+    wireObj.low_battery = api2wire_sound_sequence(apiObj.lowBattery);
+    wireObj.low_battery_percentage = api2wire_f64(apiObj.lowBatteryPercentage);
+    wireObj.on_docking = api2wire_bool(apiObj.onDocking);
+    wireObj.docking = api2wire_sound_sequence(apiObj.docking);
+  }
+
+  void _api_fill_to_wire_reconnect_policy(
+      ReconnectPolicy apiObj, wire_ReconnectPolicy wireObj) {
+    wireObj.enabled = api2wire_bool(apiObj.enabled);
+    wireObj.retry_interval_ms = api2wire_u32(apiObj.retryIntervalMs);
+    wireObj.max_retry_interval_ms = api2wire_u32(apiObj.maxRetryIntervalMs);
+    wireObj.backoff_multiplier = api2wire_f64(apiObj.backoffMultiplier);
+    wireObj.max_attempts = api2wire_u32(apiObj.maxAttempts);
+    wireObj.relocate_by_serial_number = api2wire_bool(
+        apiObj.relocateBySerialNumber);
+  }
+
+  void _api_fill_to_wire_tune_note(
+      TuneNote apiObj, wire_TuneNote wireObj) {
+    wireObj.note = api2wire_String(apiObj.note);
+    wireObj.beats = api2wire_f64(apiObj.beats);
+  }
+
+}
+
+// ignore_for_file: camel_case_types, non_constant_identifier_names, avoid_positional_boolean_parameters, annotate_overrides, constant_identifier_names
+
+// AUTO GENERATED FILE, DO NOT EDIT.
+//
+// Generated by `package:ffigen`.
+// ignore_for_file: type=lint
+
+/// generated by flutter_rust_bridge
+class Turtlebot2Wire implements FlutterRustBridgeWireBase {
+  @internal
+  late final dartApi = DartApiDl(init_frb_dart_api_dl);
+
+  /// Holds the symbol lookup function.
+  final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
+      _lookup;
+
+  /// The symbols are looked up in [dynamicLibrary].
+  Turtlebot2Wire(ffi.DynamicLibrary dynamicLibrary)
+      : _lookup = dynamicLibrary.lookup;
+
+  /// The symbols are looked up with [lookup].
+  Turtlebot2Wire.fromLookup(
+      ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
+          lookup)
+      : _lookup = lookup;
+
+  void store_dart_post_cobject(
+    DartPostCObjectFnType ptr,
+  ) {
+    return _store_dart_post_cobject(
+      ptr,
+    );
+  }
+
+  late final _store_dart_post_cobjectPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(DartPostCObjectFnType)>>(
+          'store_dart_post_cobject');
+  late final _store_dart_post_cobject =
+      _store_dart_post_cobjectPtr.asFunction<void Function(
+          DartPostCObjectFnType)>();
+
+  Object get_dart_object(
+    int ptr,
+  ) {
+    return _get_dart_object(
+      ptr,
+    );
+  }
+
+  late final _get_dart_objectPtr =
+      _lookup<ffi.NativeFunction<ffi.Handle Function(ffi.UintPtr)>>(
+          'get_dart_object');
+  late final _get_dart_object =
+      _get_dart_objectPtr.asFunction<Object Function(int)>();
+
+  void drop_dart_object(
+    int ptr,
+  ) {
+    return _drop_dart_object(
+      ptr,
+    );
+  }
+
+  late final _drop_dart_objectPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.UintPtr)>>(
+          'drop_dart_object');
+  late final _drop_dart_object =
+      _drop_dart_objectPtr.asFunction<void Function(int)>();
+
+  int new_dart_opaque(
+    Object handle,
+  ) {
+    return _new_dart_opaque(
+      handle,
+    );
+  }
+
+  late final _new_dart_opaquePtr =
+      _lookup<ffi.NativeFunction<ffi.UintPtr Function(ffi.Handle)>>(
+          'new_dart_opaque');
+  late final _new_dart_opaque =
+      _new_dart_opaquePtr.asFunction<int Function(Object)>();
+
+  int init_frb_dart_api_dl(
+    ffi.Pointer<ffi.Void> obj,
+  ) {
+    return _init_frb_dart_api_dl(
+      obj,
+    );
+  }
+
+  late final _init_frb_dart_api_dlPtr =
+      _lookup<ffi.NativeFunction<ffi.IntPtr Function(ffi.Pointer<ffi.Void>)>>(
+          'init_frb_dart_api_dl');
+  late final _init_frb_dart_api_dl =
+      _init_frb_dart_api_dlPtr.asFunction<int Function(
+          ffi.Pointer<ffi.Void>)>();
+
+  void wire_new_turtlebot(
+    int port_,
+  ) {
+    return _wire_new_turtlebot(
+      port_,
+    );
+  }
+
+  late final _wire_new_turtlebotPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
+          'wire_new_turtlebot');
+  late final _wire_new_turtlebot =
+      _wire_new_turtlebotPtr.asFunction<void Function(int)>();
+
+  void wire_spawn_turtlebot(
+    int port_,
+    int handle,
+  ) {
+    return _wire_spawn_turtlebot(
+      port_,
+      handle,
+    );
+  }
+
+  late final _wire_spawn_turtlebotPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32)>>(
+          'wire_spawn_turtlebot');
+  late final _wire_spawn_turtlebot =
+      _wire_spawn_turtlebotPtr.asFunction<void Function(int, int)>();
+
+  void wire_remove_turtlebot(
+    int port_,
+    int handle,
+  ) {
+    return _wire_remove_turtlebot(
+      port_,
+      handle,
+    );
+  }
+
+  late final _wire_remove_turtlebotPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32)>>(
+          'wire_remove_turtlebot');
+  late final _wire_remove_turtlebot =
+      _wire_remove_turtlebotPtr.asFunction<void Function(int, int)>();
+
+  void wire_receive_from_turtlebot(
+    int port_,
+    int handle,
+  ) {
+    return _wire_receive_from_turtlebot(
+      port_,
+      handle,
+    );
+  }
+
+  late final _wire_receive_from_turtlebotPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32)>>(
+          'wire_receive_from_turtlebot');
+  late final _wire_receive_from_turtlebot =
+      _wire_receive_from_turtlebotPtr.asFunction<void Function(int, int)>();
+
+  void wire_receive_device_events(
+    int port_,
+    int handle,
+  ) {
+    return _wire_receive_device_events(
+      port_,
+      handle,
+    );
+  }
+
+  late final _wire_receive_device_eventsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32)>>(
+          'wire_receive_device_events');
+  late final _wire_receive_device_events =
+      _wire_receive_device_eventsPtr.asFunction<void Function(int, int)>();
+
+  void wire_latency_command(
+    int port_,
+    int handle,
+  ) {
+    return _wire_latency_command(
+      port_,
+      handle,
+    );
+  }
+
+  late final _wire_latency_commandPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32)>>(
+          'wire_latency_command');
+  late final _wire_latency_command =
+      _wire_latency_commandPtr.asFunction<void Function(int, int)>();
+
+  void wire_decoder_stats_command(
+    int port_,
+    int handle,
+  ) {
+    return _wire_decoder_stats_command(
+      port_,
+      handle,
+    );
+  }
+
+  late final _wire_decoder_stats_commandPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32)>>(
+          'wire_decoder_stats_command');
+  late final _wire_decoder_stats_command =
+      _wire_decoder_stats_commandPtr.asFunction<void Function(int, int)>();
+
+  void wire_battery_status_command(
+    int port_,
+    int handle,
+  ) {
+    return _wire_battery_status_command(
+      port_,
+      handle,
+    );
+  }
+
+  late final _wire_battery_status_commandPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32)>>(
+          'wire_battery_status_command');
+  late final _wire_battery_status_command =
+      _wire_battery_status_commandPtr.asFunction<void Function(int, int)>();
+
+  void wire_search_port_command(
+    int port_,
+  ) {
+    return _wire_search_port_command(
+      port_,
+    );
+  }
+
+  late final _wire_search_port_commandPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
+          'wire_search_port_command');
+  late final _wire_search_port_command =
+      _wire_search_port_commandPtr.asFunction<void Function(int)>();
+
+  void wire_search_port_descriptors_command(
+    int port_,
+  ) {
+    return _wire_search_port_descriptors_command(
+      port_,
+    );
+  }
+
+  late final _wire_search_port_descriptors_commandPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
+          'wire_search_port_descriptors_command');
+  late final _wire_search_port_descriptors_command =
+      _wire_search_port_descriptors_commandPtr.asFunction<void Function(int)>();
+
+  void wire_open_port_command(
+    int port_,
+    int handle,
+    ffi.Pointer<wire_uint_8_list> serial_port,
+  ) {
+    return _wire_open_port_command(
+      port_,
+      handle,
+      serial_port,
+    );
+  }
+
+  late final _wire_open_port_commandPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(
+          ffi.Int64, ffi.Uint32, ffi.Pointer<wire_uint_8_list>)>>(
+          'wire_open_port_command');
+  late final _wire_open_port_command =
+      _wire_open_port_commandPtr.asFunction<void Function(
+          int, int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_open_port_with_config_command(
+    int port_,
+    int handle,
+    ffi.Pointer<wire_uint_8_list> serial_port,
+    ffi.Pointer<wire_ConnectionConfig> config,
+  ) {
+    return _wire_open_port_with_config_command(
+      port_,
+      handle,
+      serial_port,
+      config,
+    );
+  }
+
+  late final _wire_open_port_with_config_commandPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(
+          ffi.Int64,
+          ffi.Uint32,
+          ffi.Pointer<wire_uint_8_list>,
+          ffi.Pointer<wire_ConnectionConfig>)>>(
+          'wire_open_port_with_config_command');
+  late final _wire_open_port_with_config_command =
+      _wire_open_port_with_config_commandPtr.asFunction<void Function(
+          int,
+          int,
+          ffi.Pointer<wire_uint_8_list>,
+          ffi.Pointer<wire_ConnectionConfig>)>();
+
+  void wire_close_port_command(
+    int port_,
+    int handle,
+  ) {
+    return _wire_close_port_command(
+      port_,
+      handle,
+    );
+  }
+
+  late final _wire_close_port_commandPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32)>>(
+          'wire_close_port_command');
+  late final _wire_close_port_command =
+      _wire_close_port_commandPtr.asFunction<void Function(int, int)>();
+
+  void wire_set_event_sound_policy_command(
+    int port_,
+    int handle,
+    ffi.Pointer<wire_EventSoundPolicy> policy,
+  ) {
+    return _wire_set_event_sound_policy_command(
+      port_,
+      handle,
+      policy,
+    );
+  }
+
+  late final _wire_set_event_sound_policy_commandPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(
+          ffi.Int64, ffi.Uint32, ffi.Pointer<wire_EventSoundPolicy>)>>(
+          'wire_set_event_sound_policy_command');
+  late final _wire_set_event_sound_policy_command =
+      _wire_set_event_sound_policy_commandPtr.asFunction<void Function(
+          int, int, ffi.Pointer<wire_EventSoundPolicy>)>();
+
+  void wire_set_reconnect_policy_command(
+    int port_,
+    int handle,
+    ffi.Pointer<wire_ReconnectPolicy> policy,
+  ) {
+    return _wire_set_reconnect_policy_command(
+      port_,
+      handle,
+      policy,
+    );
+  }
+
+  late final _wire_set_reconnect_policy_commandPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(
+          ffi.Int64, ffi.Uint32, ffi.Pointer<wire_ReconnectPolicy>)>>(
+          'wire_set_reconnect_policy_command');
+  late final _wire_set_reconnect_policy_command =
+      _wire_set_reconnect_policy_commandPtr.asFunction<void Function(
+          int, int, ffi.Pointer<wire_ReconnectPolicy>)>();
+
+  void wire_start_capture_command(
+    int port_,
+    int handle,
+    ffi.Pointer<wire_uint_8_list> path,
+  ) {
+    return _wire_start_capture_command(
+      port_,
+      handle,
+      path,
+    );
+  }
+
+  late final _wire_start_capture_commandPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(
+          ffi.Int64, ffi.Uint32, ffi.Pointer<wire_uint_8_list>)>>(
+          'wire_start_capture_command');
+  late final _wire_start_capture_command =
+      _wire_start_capture_commandPtr.asFunction<void Function(
+          int, int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_stop_capture_command(
+    int port_,
+    int handle,
+  ) {
+    return _wire_stop_capture_command(
+      port_,
+      handle,
+    );
+  }
+
+  late final _wire_stop_capture_commandPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32)>>(
+          'wire_stop_capture_command');
+  late final _wire_stop_capture_command =
+      _wire_stop_capture_commandPtr.asFunction<void Function(int, int)>();
+
+  void wire_replay_mode_command(
+    int port_,
+    int handle,
+    int mode,
+    double speed,
+  ) {
+    return _wire_replay_mode_command(
+      port_,
+      handle,
+      mode,
+      speed,
+    );
+  }
+
+  late final _wire_replay_mode_commandPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(
+          ffi.Int64, ffi.Uint32, ffi.Int32, ffi.Double)>>(
+          'wire_replay_mode_command');
+  late final _wire_replay_mode_command =
+      _wire_replay_mode_commandPtr.asFunction<void Function(
+          int, int, int, double)>();
+
+  void wire_replay_pause_command(
+    int port_,
+    int handle,
+    bool paused,
+  ) {
+    return _wire_replay_pause_command(
+      port_,
+      handle,
+      paused,
+    );
+  }
+
+  late final _wire_replay_pause_commandPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(
+          ffi.Int64, ffi.Uint32, ffi.Bool)>>(
+          'wire_replay_pause_command');
+  late final _wire_replay_pause_command =
+      _wire_replay_pause_commandPtr.asFunction<void Function(int, int, bool)>();
+
+  void wire_replay_seek_command(
+    int port_,
+    int handle,
+    int position_ms,
+  ) {
+    return _wire_replay_seek_command(
+      port_,
+      handle,
+      position_ms,
+    );
+  }
+
+  late final _wire_replay_seek_commandPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(
+          ffi.Int64, ffi.Uint32, ffi.Uint64)>>(
+          'wire_replay_seek_command');
+  late final _wire_replay_seek_command =
+      _wire_replay_seek_commandPtr.asFunction<void Function(int, int, int)>();
+
+  void wire_replay_step_command(
+    int port_,
+    int handle,
+  ) {
+    return _wire_replay_step_command(
+      port_,
+      handle,
+    );
+  }
+
+  late final _wire_replay_step_commandPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32)>>(
+          'wire_replay_step_command');
+  late final _wire_replay_step_command =
+      _wire_replay_step_commandPtr.asFunction<void Function(int, int)>();
+
+  void wire_drive_command(
+    int port_,
+    int handle,
+    double linear,
+    double angular,
+  ) {
+    return _wire_drive_command(
+      port_,
+      handle,
+      linear,
+      angular,
+    );
+  }
+
+  late final _wire_drive_commandPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(
+          ffi.Int64, ffi.Uint32, ffi.Double, ffi.Double)>>(
+          'wire_drive_command');
+  late final _wire_drive_command =
+      _wire_drive_commandPtr.asFunction<void Function(
+          int, int, double, double)>();
+
+  void wire_base_control_command(
+    int port_,
+    int handle,
+    int speed,
+    int radius,
+  ) {
+    return _wire_base_control_command(
+      port_,
+      handle,
+      speed,
+      radius,
+    );
+  }
+
+  late final _wire_base_control_commandPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(
+          ffi.Int64, ffi.Uint32, ffi.Int16, ffi.Int16)>>(
+          'wire_base_control_command');
+  late final _wire_base_control_command =
+      _wire_base_control_commandPtr.asFunction<void Function(
+          int, int, int, int)>();
+
+  void wire_sound_command(
+    int port_,
+    int handle,
+    double frequency,
+    int duration_ms,
+  ) {
+    return _wire_sound_command(
+      port_,
+      handle,
+      frequency,
+      duration_ms,
+    );
+  }
+
+  late final _wire_sound_commandPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(
+          ffi.Int64, ffi.Uint32, ffi.Double, ffi.Uint8)>>(
+          'wire_sound_command');
+  late final _wire_sound_command =
+      _wire_sound_commandPtr.asFunction<void Function(int, int, double, int)>();
+
+  void wire_note_command(
+    int port_,
+    int handle,
+    ffi.Pointer<wire_uint_8_list> note,
+    int duration_ms,
+  ) {
+    return _wire_note_command(
+      port_,
+      handle,
+      note,
+      duration_ms,
+    );
+  }
+
+  late final _wire_note_commandPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(
+          ffi.Int64, ffi.Uint32, ffi.Pointer<wire_uint_8_list>, ffi.Uint8)>>(
+          'wire_note_command');
+  late final _wire_note_command =
+      _wire_note_commandPtr.asFunction<void Function(
+          int, int, ffi.Pointer<wire_uint_8_list>, int)>();
+
+  void wire_play_tune_command(
+    int port_,
+    int handle,
+    ffi.Pointer<wire_list_tune_note> notes,
+    double tempo_bpm,
+  ) {
+    return _wire_play_tune_command(
+      port_,
+      handle,
+      notes,
+      tempo_bpm,
+    );
+  }
+
+  late final _wire_play_tune_commandPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(
+          ffi.Int64,
+          ffi.Uint32,
+          ffi.Pointer<wire_list_tune_note>,
+          ffi.Double)>>(
+          'wire_play_tune_command');
+  late final _wire_play_tune_command =
+      _wire_play_tune_commandPtr.asFunction<void Function(
+          int, int, ffi.Pointer<wire_list_tune_note>, double)>();
+
+  void wire_play_rtttl_command(
+    int port_,
+    int handle,
+    ffi.Pointer<wire_uint_8_list> rtttl,
+  ) {
+    return _wire_play_rtttl_command(
+      port_,
+      handle,
+      rtttl,
+    );
+  }
+
+  late final _wire_play_rtttl_commandPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(
+          ffi.Int64, ffi.Uint32, ffi.Pointer<wire_uint_8_list>)>>(
+          'wire_play_rtttl_command');
+  late final _wire_play_rtttl_command =
+      _wire_play_rtttl_commandPtr.asFunction<void Function(
+          int, int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_tune_progress_command(
+    int port_,
+    int handle,
+  ) {
+    return _wire_tune_progress_command(
+      port_,
+      handle,
+    );
+  }
+
+  late final _wire_tune_progress_commandPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32)>>(
+          'wire_tune_progress_command');
+  late final _wire_tune_progress_command =
+      _wire_tune_progress_commandPtr.asFunction<void Function(int, int)>();
+
+  void wire_set_tune_tempo_command(
+    int port_,
+    int handle,
+    double tempo_bpm,
+  ) {
+    return _wire_set_tune_tempo_command(
+      port_,
+      handle,
+      tempo_bpm,
+    );
+  }
+
+  late final _wire_set_tune_tempo_commandPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(
+          ffi.Int64, ffi.Uint32, ffi.Double)>>(
+          'wire_set_tune_tempo_command');
+  late final _wire_set_tune_tempo_command =
+      _wire_set_tune_tempo_commandPtr.asFunction<void Function(
+          int, int, double)>();
+
+  void wire_stop_tune_command(
+    int port_,
+    int handle,
+  ) {
+    return _wire_stop_tune_command(
+      port_,
+      handle,
+    );
+  }
+
+  late final _wire_stop_tune_commandPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32)>>(
+          'wire_stop_tune_command');
+  late final _wire_stop_tune_command =
+      _wire_stop_tune_commandPtr.asFunction<void Function(int, int)>();
+
+  void wire_sound_sequence_command(
+    int port_,
+    int handle,
+    int seq,
+  ) {
+    return _wire_sound_sequence_command(
+      port_,
+      handle,
+      seq,
+    );
+  }
+
+  late final _wire_sound_sequence_commandPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(
+          ffi.Int64, ffi.Uint32, ffi.Int32)>>(
+          'wire_sound_sequence_command');
+  late final _wire_sound_sequence_command =
+      _wire_sound_sequence_commandPtr.asFunction<void Function(
+          int, int, int)>();
+
+  void wire_request_extra_command(
+    int port_,
+    int handle,
+    bool hw_ver,
+    bool fw_ver,
+    bool udid,
+  ) {
+    return _wire_request_extra_command(
+      port_,
+      handle,
+      hw_ver,
+      fw_ver,
+      udid,
+    );
+  }
+
+  late final _wire_request_extra_commandPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(
+          ffi.Int64, ffi.Uint32, ffi.Bool, ffi.Bool, ffi.Bool)>>(
+          'wire_request_extra_command');
+  late final _wire_request_extra_command =
+      _wire_request_extra_commandPtr.asFunction<void Function(
+          int, int, bool, bool, bool)>();
+
+  void wire_general_purpose_output_command(
+    int port_,
+    int handle,
+    bool d_out_ch0,
+    bool d_out_ch1,
+    bool d_out_ch2,
+    bool d_out_ch3,
+    bool power_3v3,
+    bool power_5v0,
+    bool power_12v5a,
+    bool power_12v1a5,
+    bool red_led1,
+    bool red_led2,
+    bool green_led1,
+    bool green_led2,
+  ) {
+    return _wire_general_purpose_output_command(
+      port_,
+      handle,
+      d_out_ch0,
+      d_out_ch1,
+      d_out_ch2,
+      d_out_ch3,
+      power_3v3,
+      power_5v0,
+      power_12v5a,
+      power_12v1a5,
+      red_led1,
+      red_led2,
+      green_led1,
+      green_led2,
+    );
+  }
+
+  late final _wire_general_purpose_output_commandPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(
+          ffi.Int64,
+          ffi.Uint32,
+          ffi.Bool,
+          ffi.Bool,
+          ffi.Bool,
+          ffi.Bool,
+          ffi.Bool,
+          ffi.Bool,
+          ffi.Bool,
+          ffi.Bool,
+          ffi.Bool,
+          ffi.Bool,
+          ffi.Bool,
+          ffi.Bool)>>(
+          'wire_general_purpose_output_command');
+  late final _wire_general_purpose_output_command =
+      _wire_general_purpose_output_commandPtr.asFunction<void Function(
+          int,
+          int,
+          bool,
+          bool,
+          bool,
+          bool,
+          bool,
+          bool,
+          bool,
+          bool,
+          bool,
+          bool,
+          bool,
+          bool)>();
+
+  void wire_set_controller_gain_command(
+    int port_,
+    int handle,
+    bool is_user_configured,
+    int p,
+    double i,
+    int d,
+  ) {
+    return _wire_set_controller_gain_command(
+      port_,
+      handle,
+      is_user_configured,
+      p,
+      i,
+      d,
+    );
+  }
+
+  late final _wire_set_controller_gain_commandPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(
+          ffi.Int64, ffi.Uint32, ffi.Bool, ffi.Uint32, ffi.Float, ffi.Uint32)>>(
+          'wire_set_controller_gain_command');
+  late final _wire_set_controller_gain_command =
+      _wire_set_controller_gain_commandPtr.asFunction<void Function(
+          int, int, bool, int, double, int)>();
+
+  void wire_get_controller_gain(
+    int port_,
+    int handle,
+  ) {
+    return _wire_get_controller_gain(
+      port_,
+      handle,
+    );
+  }
+
+  late final _wire_get_controller_gainPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32)>>(
+          'wire_get_controller_gain');
+  late final _wire_get_controller_gain =
+      _wire_get_controller_gainPtr.asFunction<void Function(int, int)>();
+
+  ffi.Pointer<wire_ConnectionConfig> new_box_autoadd_connection_config_0(
+  ) {
+    return _new_box_autoadd_connection_config_0(
+    );
+  }
+
+  late final _new_box_autoadd_connection_config_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_ConnectionConfig> Function()>>(
+          'new_box_autoadd_connection_config_0');
+  late final _new_box_autoadd_connection_config_0 =
+      _new_box_autoadd_connection_config_0Ptr.asFunction<ffi.Pointer<wire_ConnectionConfig> Function()>();
+
+  ffi.Pointer<wire_EventSoundPolicy> new_box_autoadd_event_sound_policy_0(
+  ) {
+    return _new_box_autoadd_event_sound_policy_0(
+    );
+  }
+
+  late final _new_box_autoadd_event_sound_policy_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_EventSoundPolicy> Function()>>(
+          'new_box_autoadd_event_sound_policy_0');
+  late final _new_box_autoadd_event_sound_policy_0 =
+      _new_box_autoadd_event_sound_policy_0Ptr.asFunction<ffi.Pointer<wire_EventSoundPolicy> Function()>();
+
+  ffi.Pointer<wire_ReconnectPolicy> new_box_autoadd_reconnect_policy_0(
+  ) {
+    return _new_box_autoadd_reconnect_policy_0(
+    );
+  }
+
+  late final _new_box_autoadd_reconnect_policy_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_ReconnectPolicy> Function()>>(
+          'new_box_autoadd_reconnect_policy_0');
+  late final _new_box_autoadd_reconnect_policy_0 =
+      _new_box_autoadd_reconnect_policy_0Ptr.asFunction<ffi.Pointer<wire_ReconnectPolicy> Function()>();
+
+  ffi.Pointer<wire_list_tune_note> new_list_tune_note_0(
+    int len,
+  ) {
+    return _new_list_tune_note_0(
+      len,
+    );
+  }
+
+  late final _new_list_tune_note_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_list_tune_note> Function(
+          ffi.Int32)>>(
+          'new_list_tune_note_0');
+  late final _new_list_tune_note_0 =
+      _new_list_tune_note_0Ptr.asFunction<ffi.Pointer<wire_list_tune_note> Function(int)>();
+
+  ffi.Pointer<wire_uint_8_list> new_uint_8_list_0(
+    int len,
+  ) {
+    return _new_uint_8_list_0(
+      len,
+    );
+  }
+
+  late final _new_uint_8_list_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_uint_8_list> Function(
+          ffi.Int32)>>(
+          'new_uint_8_list_0');
+  late final _new_uint_8_list_0 =
+      _new_uint_8_list_0Ptr.asFunction<ffi.Pointer<wire_uint_8_list> Function(
+          int)>();
+
+  void free_WireSyncReturn(
+    WireSyncReturn ptr,
+  ) {
+    return _free_WireSyncReturn(
+      ptr,
+    );
+  }
+
+  late final _free_WireSyncReturnPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(WireSyncReturn)>>(
+          'free_WireSyncReturn');
+  late final _free_WireSyncReturn =
+      _free_WireSyncReturnPtr.asFunction<void Function(WireSyncReturn)>();
+
+}
+
+class _Dart_Handle extends ffi.Opaque {}
+
+class wire_ConnectionConfig extends ffi.Struct {
+  @ffi.Uint32()
+  external int baud_rate;
+
+  @ffi.Uint32()
+  external int read_timeout_ms;
+
+  @ffi.Int32()
+  external int read_strategy;
+
+  @ffi.Uint32()
+  external int poll_interval_ms;
+
+  @ffi.Uint32()
+  external int buffer_size;
+
+  @ffi.Int32()
+  external int flow_control;
+}
+
+class wire_EventSoundPolicy extends ffi.Struct {
+  @ffi.Bool()
+  external bool enabled;
+
+  @ffi.Bool()
+  external bool on_connected;
+
+  @ffi.Int32()
+  external int connected;
+
+  @ffi.Bool()
+  external bool on_bump;
+
+  @ffi.Int32()
+  external int bump;
+
+  @ffi.Bool()
+  external bool on_low_battery;
+
+  @ffi.Int32()
+  external int low_battery;
+
+  @ffi.Double()
+  external double low_battery_percentage;
+
+  @ffi.Bool()
+  external bool on_docking;
+
+  @ffi.Int32()
+  external int docking;
+}
+
+class wire_list_tune_note extends ffi.Struct {
+  external ffi.Pointer<wire_TuneNote> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+class wire_ReconnectPolicy extends ffi.Struct {
+  @ffi.Bool()
+  external bool enabled;
+
+  @ffi.Uint32()
+  external int retry_interval_ms;
+
+  @ffi.Uint32()
+  external int max_retry_interval_ms;
+
+  @ffi.Double()
+  external double backoff_multiplier;
+
+  @ffi.Uint32()
+  external int max_attempts;
+
+  @ffi.Bool()
+  external bool relocate_by_serial_number;
+}
+
+class wire_TuneNote extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> note;
+
+  @ffi.Double()
+  external double beats;
+}
+
+class wire_uint_8_list extends ffi.Struct {
+  external ffi.Pointer<ffi.Uint8> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+typedef DartPostCObjectFnType = ffi.Pointer<
+    ffi.NativeFunction<
+        ffi.Bool Function(DartPort port_id, ffi.Pointer<ffi.Void> message)>>;
+typedef DartPort = ffi.Int64;
@@ -6,6 +6,8 @@ import 'dart:ffi';
 const ttbPath = 'dlib/libturtlebot2.so';
 late final ttbDlib = DynamicLibrary.open(ttbPath);
 late final ttb = Turtlebot2Impl(ttbDlib);
+// The session every call below goes to
+late final ttbHandle = ttb.newTurtlebot();
 
 void main() {
   _spawn();
@@ -13,10 +15,11 @@ void main() {
 }
 
 Future<void> _spawn() async {
-  final a = ttb.spawnTurtlebot();
+  final handle = await ttbHandle;
+  final a = ttb.spawnTurtlebot(handle: handle);
   await for (final v in a) {
     debugPrint("v - " + v.toString());
-    var f = await ttb.receiveFromTurtlebot();
+    var f = await ttb.receiveFromTurtlebot(handle: handle);
     debugPrint("f - " + f.length.toString());
   }
 }
@@ -91,6 +94,7 @@ class _MyHomePageState extends State<MyHomePage> {
     });
 
     // await ttb.sendToTurtlebot();
-    await ttb.openPortCommand(serialPort: "/dev/ttyUSB0");
+    await ttb.openPortCommand(
+        handle: await ttbHandle, serialPort: "/dev/ttyUSB0");
   }
 }
@@ -0,0 +1,71 @@
+// A software Kobuki that speaks the wire protocol on a pseudo-terminal.
+//
+// $ cargo run -p kobuki_sim
+// Then open the printed /dev/pts/N with open_port_command.
+
+mod protocol;
+mod robot;
+
+use std::{
+    io::{ErrorKind, Read, Write},
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use serialport::{ClearBuffer, SerialPort, TTYPort};
+
+use crate::protocol::CommandParser;
+use crate::robot::Robot;
+
+// Kobuki sends feedbacks at 50 Hz
+const FEEDBACK_PERIOD: Duration = Duration::from_millis(20);
+// Don't block the loop if nobody reads the other side
+const WRITE_TIMEOUT: Duration = Duration::from_millis(1);
+
+fn main() -> Result<()> {
+    let (mut master, mut slave) = TTYPort::pair()?;
+    // Keep the slave side opened so the master doesn't get EIO,
+    // but let the dashboard open it as well.
+    slave.set_exclusive(false)?;
+    let slave_name = slave.name().unwrap_or_default();
+    println!("Kobuki simulator is ready => {}", slave_name);
+
+    let mut parser = CommandParser::default();
+    let mut robot = Robot::new();
+    let mut buffer = [0u8; 1024];
+    let mut last = Instant::now();
+    let mut next = last + FEEDBACK_PERIOD;
+
+    loop {
+        // Serve commands until the next feedback is due
+        let now = Instant::now();
+        if now < next {
+            master.set_timeout(next - now)?;
+            match master.read(&mut buffer) {
+                Ok(l) => {
+                    for c in parser.push(&buffer[..l]) {
+                        robot.apply(c);
+                    }
+                }
+                Err(e) if e.kind() == ErrorKind::TimedOut => {}
+                Err(e) => return Err(e.into()),
+            }
+            continue;
+        }
+
+        robot.step((now - last).as_secs_f64());
+        last = now;
+        next += FEEDBACK_PERIOD;
+
+        let packet = robot.feedback();
+        master.set_timeout(WRITE_TIMEOUT)?;
+        match master.write_all(&packet) {
+            Ok(_) => {}
+            // Nobody is reading, so throw away the stale feedbacks
+            Err(e) if e.kind() == ErrorKind::TimedOut => {
+                master.clear(ClearBuffer::Output)?;
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+}
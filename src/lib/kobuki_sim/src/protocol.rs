@@ -0,0 +1,237 @@
+// Kobuki wire protocol for the simulator side:
+// - Parses incoming command packets (Flutter => robot)
+// - Builds feedback packets (robot => Flutter)
+//
+// Packet = 0xaa 0x55 | length | sub-payloads (ID, size, data...) | checksum
+// The length covers the sub-payloads only, and the checksum is XOR of length + sub-payloads.
+
+pub const HEADER_0: u8 = 0xaa;
+pub const HEADER_1: u8 = 0x55;
+
+// Command IDs
+pub const CMD_BASE_CONTROL: u8 = 1;
+pub const CMD_SOUND: u8 = 3;
+pub const CMD_SOUND_SEQUENCE: u8 = 4;
+pub const CMD_REQUEST_EXTRA: u8 = 9;
+pub const CMD_GENERAL_PURPOSE_OUTPUT: u8 = 12;
+pub const CMD_SET_CONTROLLER_GAIN: u8 = 13;
+pub const CMD_GET_CONTROLLER_GAIN: u8 = 14;
+
+// Feedback IDs
+pub const FDB_BASIC_SENSOR: u8 = 1;
+pub const FDB_DOCKING_IR: u8 = 3;
+pub const FDB_INERTIAL_SENSOR: u8 = 4;
+pub const FDB_CLIFF: u8 = 5;
+pub const FDB_CURRENT: u8 = 6;
+pub const FDB_HARDWARE_VERSION: u8 = 10;
+pub const FDB_FIRMWARE_VERSION: u8 = 11;
+pub const FDB_RAW_DATA_3_AXIS_GYRO: u8 = 13;
+pub const FDB_GENERAL_PURPOSE_INPUT: u8 = 16;
+pub const FDB_UNIQUE_DEVICE_ID: u8 = 19;
+pub const FDB_CONTROLLER_INFO: u8 = 21;
+
+// Request extra flags
+pub const EXTRA_HARDWARE_VERSION: u16 = 0x01;
+pub const EXTRA_FIRMWARE_VERSION: u16 = 0x02;
+pub const EXTRA_UNIQUE_DEVICE_ID: u16 = 0x08;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    BaseControl { speed: i16, radius: i16 },
+    Sound { note: u16, duration: u8 },
+    SoundSequence(u8),
+    RequestExtra(u16),
+    GeneralPurposeOutput(u16),
+    SetControllerGain { ty: u8, p: u32, i: u32, d: u32 },
+    GetControllerGain,
+    Unknown { id: u8, data: Vec<u8> },
+}
+
+// Collects incoming bytes and takes complete packets out of them
+#[derive(Default)]
+pub struct CommandParser {
+    buffer: Vec<u8>,
+}
+
+impl CommandParser {
+    pub fn push(&mut self, bytes: &[u8]) -> Vec<Command> {
+        self.buffer.extend_from_slice(bytes);
+        let mut commands = Vec::new();
+
+        loop {
+            // Drop everything before the preambles
+            match self
+                .buffer
+                .windows(2)
+                .position(|w| w[0] == HEADER_0 && w[1] == HEADER_1)
+            {
+                Some(start) => {
+                    self.buffer.drain(..start);
+                }
+                None => {
+                    // Keep the last byte since it can be the first preamble
+                    let keep = self.buffer.last() == Some(&HEADER_0);
+                    self.buffer.clear();
+                    if keep {
+                        self.buffer.push(HEADER_0);
+                    }
+                    break;
+                }
+            }
+
+            if self.buffer.len() < 3 {
+                break;
+            }
+            let length = self.buffer[2] as usize;
+            if self.buffer.len() < length + 4 {
+                break;
+            }
+
+            let packet: Vec<u8> = self.buffer.drain(..length + 4).collect();
+            if checksum(&packet[2..length + 3]) != packet[length + 3] {
+                // Resync from the byte after the broken preambles
+                let mut rest = packet[1..].to_vec();
+                rest.extend_from_slice(&self.buffer);
+                self.buffer = rest;
+                continue;
+            }
+            commands.extend(parse_sub_payloads(&packet[3..length + 3]));
+        }
+        commands
+    }
+}
+
+fn parse_sub_payloads(payload: &[u8]) -> Vec<Command> {
+    let mut commands = Vec::new();
+    let mut index = 0;
+    while index + 2 <= payload.len() {
+        let id = payload[index];
+        let size = payload[index + 1] as usize;
+        let Some(data) = payload.get(index + 2..index + 2 + size) else {
+            break;
+        };
+        commands.push(parse_command(id, data));
+        index += 2 + size;
+    }
+    commands
+}
+
+fn parse_command(id: u8, data: &[u8]) -> Command {
+    let unknown = || Command::Unknown {
+        id,
+        data: data.to_vec(),
+    };
+    match (id, data.len()) {
+        (CMD_BASE_CONTROL, 4) => Command::BaseControl {
+            speed: i16::from_le_bytes([data[0], data[1]]),
+            radius: i16::from_le_bytes([data[2], data[3]]),
+        },
+        (CMD_SOUND, 3) => Command::Sound {
+            note: u16::from_le_bytes([data[0], data[1]]),
+            duration: data[2],
+        },
+        (CMD_SOUND_SEQUENCE, 1) => Command::SoundSequence(data[0]),
+        (CMD_REQUEST_EXTRA, 2) => Command::RequestExtra(u16::from_le_bytes([data[0], data[1]])),
+        (CMD_GENERAL_PURPOSE_OUTPUT, 2) => {
+            Command::GeneralPurposeOutput(u16::from_le_bytes([data[0], data[1]]))
+        }
+        (CMD_SET_CONTROLLER_GAIN, 13) => Command::SetControllerGain {
+            ty: data[0],
+            p: u32::from_le_bytes([data[1], data[2], data[3], data[4]]),
+            i: u32::from_le_bytes([data[5], data[6], data[7], data[8]]),
+            d: u32::from_le_bytes([data[9], data[10], data[11], data[12]]),
+        },
+        (CMD_GET_CONTROLLER_GAIN, 1) => Command::GetControllerGain,
+        _ => unknown(),
+    }
+}
+
+pub fn checksum(bytes: &[u8]) -> u8 {
+    bytes.iter().fold(0, |acc, c| acc ^ c)
+}
+
+// Builds a feedback packet out of sub-payloads
+#[derive(Default)]
+pub struct PacketBuilder {
+    payload: Vec<u8>,
+}
+
+impl PacketBuilder {
+    pub fn sub_payload(&mut self, id: u8, data: &[u8]) -> &mut Self {
+        self.payload.push(id);
+        self.payload.push(data.len() as u8);
+        self.payload.extend_from_slice(data);
+        self
+    }
+
+    pub fn build(&self) -> Vec<u8> {
+        let mut packet = vec![HEADER_0, HEADER_1, self.payload.len() as u8];
+        packet.extend_from_slice(&self.payload);
+        packet.push(checksum(&packet[2..]));
+        packet
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn base_control(speed: i16, radius: i16) -> Vec<u8> {
+        let mut data = speed.to_le_bytes().to_vec();
+        data.extend_from_slice(&radius.to_le_bytes());
+        PacketBuilder::default()
+            .sub_payload(CMD_BASE_CONTROL, &data)
+            .build()
+    }
+
+    #[test]
+    fn split_across_pushes() {
+        let packet = base_control(100, 0);
+        let mut parser = CommandParser::default();
+        for (i, b) in packet.iter().enumerate() {
+            let commands = parser.push(&[*b]);
+            if i + 1 < packet.len() {
+                assert!(commands.is_empty());
+            } else {
+                assert_eq!(
+                    commands,
+                    vec![Command::BaseControl {
+                        speed: 100,
+                        radius: 0
+                    }]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn bad_checksum_then_good_packet() {
+        let mut broken = base_control(100, 0);
+        *broken.last_mut().unwrap() ^= 0xff;
+        let mut bytes = broken;
+        bytes.extend_from_slice(&base_control(-50, 1));
+
+        let mut parser = CommandParser::default();
+        assert_eq!(
+            parser.push(&bytes),
+            vec![Command::BaseControl {
+                speed: -50,
+                radius: 1
+            }]
+        );
+    }
+
+    #[test]
+    fn preambles_inside_payload() {
+        // 0x55aa => aa 55 on the wire
+        let speed = i16::from_le_bytes([HEADER_0, HEADER_1]);
+        let mut bytes = vec![0x00, HEADER_0];
+        bytes.extend_from_slice(&base_control(speed, 0));
+
+        let mut parser = CommandParser::default();
+        assert_eq!(
+            parser.push(&bytes),
+            vec![Command::BaseControl { speed, radius: 0 }]
+        );
+    }
+}
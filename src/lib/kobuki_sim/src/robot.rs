@@ -0,0 +1,304 @@
+use std::f64::consts::PI;
+
+use crate::protocol::*;
+
+// Geometry of Kobuki
+const WHEEL_BASE_MM: f64 = 230.0;
+// 2578.33 ticks per wheel revolution, 70 mm wheel diameter
+const TICKS_PER_MM: f64 = 11.724;
+const MAX_WHEEL_SPEED_MM_S: f64 = 700.0;
+
+// The gyro runs at 100 Hz while the feedback goes out at 50 Hz
+const GYRO_RATE_HZ: f64 = 100.0;
+// L3G4200D at 250 dps => 8.75 mdps per digit
+const GYRO_DIGIT_PER_DEG_S: f64 = 1.0 / 0.00875;
+
+// 4S pack, full charge (0.1 V unit)
+const BATTERY_FULL: f64 = 167.0;
+const BATTERY_EMPTY: f64 = 132.0;
+// Roughly 2 hours of driving from full to empty (0.1 V per second)
+const BATTERY_DRAIN_MOVING: f64 = (BATTERY_FULL - BATTERY_EMPTY) / 7200.0;
+const BATTERY_DRAIN_IDLE: f64 = BATTERY_DRAIN_MOVING / 4.0;
+
+// A floor under all cliff sensors
+const CLIFF_FLOOR: u16 = 2200;
+
+// The sound unit of Kobuki => note = 1 / (frequency * a)
+const SOUND_A: f64 = 0.00000275;
+
+// Robot is a virtual differential drive Kobuki
+pub struct Robot {
+    // Last BaseControl
+    speed: i16,
+    radius: i16,
+    // Pose (mm, rad)
+    x: f64,
+    y: f64,
+    heading: f64,
+    // Wheel states (mm/s, ticks)
+    left_speed: f64,
+    right_speed: f64,
+    left_ticks: f64,
+    right_ticks: f64,
+    angular_rate: f64,
+    // Others
+    time_ms: f64,
+    battery: f64,
+    gyro_due: f64,
+    gyro_frame_id: u8,
+    general_purpose_output: u16,
+    gains: (u8, u32, u32, u32),
+    pending_extra: u16,
+    pending_controller_info: bool,
+}
+
+impl Robot {
+    pub fn new() -> Robot {
+        Robot {
+            speed: 0,
+            radius: 0,
+            x: 0.0,
+            y: 0.0,
+            heading: 0.0,
+            left_speed: 0.0,
+            right_speed: 0.0,
+            left_ticks: 0.0,
+            right_ticks: 0.0,
+            angular_rate: 0.0,
+            time_ms: 0.0,
+            battery: BATTERY_FULL - 3.0,
+            gyro_due: 0.0,
+            gyro_frame_id: 0,
+            general_purpose_output: 0,
+            // Factory default gains (x1000)
+            gains: (0, 100 * 1000, 100, 2 * 1000),
+            pending_extra: 0,
+            pending_controller_info: false,
+        }
+    }
+
+    pub fn apply(&mut self, command: Command) {
+        match command {
+            Command::BaseControl { speed, radius } => {
+                if speed != self.speed || radius != self.radius {
+                    println!("BaseControl: speed {} mm/s, radius {} mm", speed, radius);
+                }
+                self.speed = speed;
+                self.radius = radius;
+            }
+            Command::Sound { note, duration } => {
+                let freq = if note == 0 {
+                    0.0
+                } else {
+                    1.0 / (note as f64 * SOUND_A)
+                };
+                println!("Sound: {:.0} Hz for {} ms", freq, duration);
+            }
+            Command::SoundSequence(seq) => {
+                println!("SoundSequence: {}", seq);
+            }
+            Command::RequestExtra(flags) => {
+                println!("RequestExtra: {:#06x}", flags);
+                self.pending_extra |= flags;
+            }
+            Command::GeneralPurposeOutput(flags) => {
+                if flags != self.general_purpose_output {
+                    println!("GeneralPurposeOutput: {:#06x}", flags);
+                }
+                self.general_purpose_output = flags;
+            }
+            Command::SetControllerGain { ty, p, i, d } => {
+                println!("SetControllerGain: type {} P {} I {} D {}", ty, p, i, d);
+                self.gains = (ty, p, i, d);
+            }
+            Command::GetControllerGain => {
+                self.pending_controller_info = true;
+            }
+            Command::Unknown { id, data } => {
+                println!("Unknown command: id {} data {:02x?}", id, data);
+            }
+        }
+    }
+
+    // Moves the robot by the elapsed time (seconds)
+    pub fn step(&mut self, dt: f64) {
+        let (left, right) = wheel_speeds(self.speed, self.radius);
+        self.left_speed = left.clamp(-MAX_WHEEL_SPEED_MM_S, MAX_WHEEL_SPEED_MM_S);
+        self.right_speed = right.clamp(-MAX_WHEEL_SPEED_MM_S, MAX_WHEEL_SPEED_MM_S);
+
+        self.left_ticks += self.left_speed * dt * TICKS_PER_MM;
+        self.right_ticks += self.right_speed * dt * TICKS_PER_MM;
+
+        let linear = (self.left_speed + self.right_speed) / 2.0;
+        self.angular_rate = (self.right_speed - self.left_speed) / WHEEL_BASE_MM;
+        self.heading += self.angular_rate * dt;
+        self.x += linear * self.heading.cos() * dt;
+        self.y += linear * self.heading.sin() * dt;
+
+        self.time_ms += dt * 1000.0;
+        self.gyro_due += dt * GYRO_RATE_HZ;
+
+        let moving = self.left_speed != 0.0 || self.right_speed != 0.0;
+        let drain = if moving {
+            BATTERY_DRAIN_MOVING
+        } else {
+            BATTERY_DRAIN_IDLE
+        };
+        self.battery = (self.battery - drain * dt).max(BATTERY_EMPTY);
+    }
+
+    // Builds a feedback packet of the current state like the firmware does at 50 Hz
+    pub fn feedback(&mut self) -> Vec<u8> {
+        let mut builder = PacketBuilder::default();
+        builder
+            .sub_payload(FDB_BASIC_SENSOR, &self.basic_sensor())
+            .sub_payload(FDB_DOCKING_IR, &[0, 0, 0])
+            .sub_payload(FDB_INERTIAL_SENSOR, &self.inertial_sensor())
+            .sub_payload(FDB_CLIFF, &cliff())
+            .sub_payload(FDB_CURRENT, &self.current())
+            .sub_payload(FDB_RAW_DATA_3_AXIS_GYRO, &self.gyro())
+            .sub_payload(FDB_GENERAL_PURPOSE_INPUT, &[0; 16]);
+
+        if self.pending_extra & EXTRA_HARDWARE_VERSION != 0 {
+            // patch, minor, major, unused
+            builder.sub_payload(FDB_HARDWARE_VERSION, &[4, 0, 1, 0]);
+        }
+        if self.pending_extra & EXTRA_FIRMWARE_VERSION != 0 {
+            builder.sub_payload(FDB_FIRMWARE_VERSION, &[0, 2, 1, 0]);
+        }
+        if self.pending_extra & EXTRA_UNIQUE_DEVICE_ID != 0 {
+            builder.sub_payload(FDB_UNIQUE_DEVICE_ID, &[0x5a; 12]);
+        }
+        self.pending_extra = 0;
+
+        if self.pending_controller_info {
+            let (ty, p, i, d) = self.gains;
+            let mut data = vec![ty];
+            data.extend_from_slice(&p.to_le_bytes());
+            data.extend_from_slice(&i.to_le_bytes());
+            data.extend_from_slice(&d.to_le_bytes());
+            builder.sub_payload(FDB_CONTROLLER_INFO, &data);
+            self.pending_controller_info = false;
+        }
+
+        builder.build()
+    }
+
+    fn basic_sensor(&self) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(&(self.time_ms as u64 as u16).to_le_bytes());
+        data.push(0); // bumper
+        data.push(0); // wheel drop
+        data.push(0); // cliff
+        data.extend_from_slice(&(self.left_ticks.round() as i64 as u16).to_le_bytes());
+        data.extend_from_slice(&(self.right_ticks.round() as i64 as u16).to_le_bytes());
+        data.push(pwm(self.left_speed) as u8);
+        data.push(pwm(self.right_speed) as u8);
+        data.push(0); // button
+        data.push(0); // charger => discharging
+        data.push(self.battery.round() as u8);
+        data.push(0); // overcurrent
+        data
+    }
+
+    fn inertial_sensor(&self) -> Vec<u8> {
+        // Hundredths of a degree, wrapped into -180 ~ 180
+        let heading = (self.heading + PI).rem_euclid(2.0 * PI) - PI;
+        let angle = (heading.to_degrees() * 100.0).round() as i16;
+        let angle_rate = (self.angular_rate.to_degrees() * 100.0).round() as i16;
+        let mut data = Vec::new();
+        data.extend_from_slice(&angle.to_le_bytes());
+        data.extend_from_slice(&angle_rate.to_le_bytes());
+        data.extend_from_slice(&[0, 0, 0]); // unused
+        data
+    }
+
+    fn current(&self) -> Vec<u8> {
+        // 10 mA unit, ~0.5 A at full speed
+        let left = (self.left_speed.abs() / MAX_WHEEL_SPEED_MM_S * 50.0) as u8;
+        let right = (self.right_speed.abs() / MAX_WHEEL_SPEED_MM_S * 50.0) as u8;
+        vec![left, right]
+    }
+
+    fn gyro(&mut self) -> Vec<u8> {
+        // 2 or 3 samples per packet depending on the timing
+        let count = (self.gyro_due as usize).clamp(1, 3);
+        self.gyro_due = (self.gyro_due - count as f64).max(0.0);
+        self.gyro_frame_id = self.gyro_frame_id.wrapping_add(count as u8);
+
+        let z = (self.angular_rate.to_degrees() * GYRO_DIGIT_PER_DEG_S).round() as i16;
+        let mut data = vec![self.gyro_frame_id, (count * 3) as u8];
+        for _ in 0..count {
+            data.extend_from_slice(&0i16.to_le_bytes());
+            data.extend_from_slice(&0i16.to_le_bytes());
+            data.extend_from_slice(&z.to_le_bytes());
+        }
+        data
+    }
+}
+
+fn cliff() -> Vec<u8> {
+    let mut data = Vec::new();
+    for _ in 0..3 {
+        data.extend_from_slice(&CLIFF_FLOOR.to_le_bytes());
+    }
+    data
+}
+
+fn pwm(speed: f64) -> i8 {
+    (speed / MAX_WHEEL_SPEED_MM_S * 100.0).round() as i8
+}
+
+// Kobuki BaseControl => wheel speeds (mm/s)
+// - radius 0 => straight
+// - radius 1 => rotate in place, speed is the speed of the wheels
+// - others => arc, speed is the speed of the outer wheel
+fn wheel_speeds(speed: i16, radius: i16) -> (f64, f64) {
+    let speed = speed as f64;
+    match radius {
+        0 => (speed, speed),
+        1 => (-speed, speed),
+        r => {
+            let r = r as f64;
+            let outer = if r > 0.0 {
+                r + WHEEL_BASE_MM / 2.0
+            } else {
+                r - WHEEL_BASE_MM / 2.0
+            };
+            let angular = speed / outer;
+            (
+                angular * (r - WHEEL_BASE_MM / 2.0),
+                angular * (r + WHEEL_BASE_MM / 2.0),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: (f64, f64), expected: (f64, f64)) {
+        assert!((actual.0 - expected.0).abs() < 1e-9, "{:?}", actual);
+        assert!((actual.1 - expected.1).abs() < 1e-9, "{:?}", actual);
+    }
+
+    #[test]
+    fn wheel_speeds_straight_and_rotate() {
+        assert_close(wheel_speeds(200, 0), (200.0, 200.0));
+        assert_close(wheel_speeds(-200, 0), (-200.0, -200.0));
+        assert_close(wheel_speeds(100, 1), (-100.0, 100.0));
+        assert_close(wheel_speeds(-100, 1), (100.0, -100.0));
+    }
+
+    #[test]
+    fn wheel_speeds_arc() {
+        // Left turn => the right wheel is the outer one
+        let inner = 100.0 * (300.0 - WHEEL_BASE_MM / 2.0) / (300.0 + WHEEL_BASE_MM / 2.0);
+        assert_close(wheel_speeds(100, 300), (inner, 100.0));
+        // Right turn => the left wheel is the outer one
+        assert_close(wheel_speeds(100, -300), (100.0, inner));
+        // Backwards
+        assert_close(wheel_speeds(-100, 300), (-inner, -100.0));
+    }
+}
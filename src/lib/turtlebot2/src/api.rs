@@ -8,7 +8,11 @@ use crossbeam_channel as crossbeam;
 use derivative::*;
 use flutter_rust_bridge::{StreamSink, SyncReturn};
 
+use crate::capture::*;
+use crate::rtttl::*;
 use crate::rx::*;
+use crate::transport::ReplayControl;
+use crate::tune::*;
 use crate::turtlebot2::*;
 use crate::tx::*;
 
@@ -28,6 +32,12 @@ pub struct Feedback {
     pub general_purpose_input: GeneralPurposeInput,
     pub unique_device_id: UniqueDeviceId,
     pub controller_info: ControllerInfo,
+    // Estimated from the basic sensor data so far (see battery.rs)
+    pub battery: BatteryStatus,
+    // Unwrapped from the basic sensor data so far (see encoder.rs)
+    pub encoders: EncoderTicks,
+    // Sub-payloads with ids this version doesn't know
+    pub unknown_sub_payloads: Vec<UnknownSubPayload>,
 }
 
 #[derive(Debug, Clone, Derivative)]
@@ -44,8 +54,66 @@ pub struct BasicSensor {
     pub right_pwm: u32,
     pub button: u32,
     pub charger: u32,
-    pub battery: u32,
+    // In 0.1 V
+    pub battery: u8,
+    pub battery_volts: f64,
     pub overcurrent_flags: u32,
+    // The raw flags above by name
+    pub bumpers: BumperFlags,
+    pub wheel_drops: WheelDropFlags,
+    pub cliffs: CliffFlags,
+    pub buttons: ButtonFlags,
+    pub charger_state: ChargerState,
+    pub overcurrents: OvercurrentFlags,
+}
+
+#[derive(Debug, Clone, Derivative)]
+#[derivative(Default)]
+pub struct BumperFlags {
+    pub left: bool,
+    pub center: bool,
+    pub right: bool,
+}
+
+#[derive(Debug, Clone, Derivative)]
+#[derivative(Default)]
+pub struct WheelDropFlags {
+    pub left: bool,
+    pub right: bool,
+}
+
+#[derive(Debug, Clone, Derivative)]
+#[derivative(Default)]
+pub struct CliffFlags {
+    pub left: bool,
+    pub center: bool,
+    pub right: bool,
+}
+
+#[derive(Debug, Clone, Derivative)]
+#[derivative(Default)]
+pub struct ButtonFlags {
+    pub b0: bool,
+    pub b1: bool,
+    pub b2: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Derivative)]
+#[derivative(Default)]
+pub enum ChargerState {
+    #[derivative(Default)]
+    Discharging,
+    DockingCharged,
+    DockingCharging,
+    AdapterCharged,
+    AdapterCharging,
+}
+
+#[derive(Debug, Clone, Derivative)]
+#[derivative(Default)]
+pub struct OvercurrentFlags {
+    pub left: bool,
+    pub right: bool,
 }
 
 #[derive(Debug, Clone, Derivative)]
@@ -60,8 +128,14 @@ pub struct DockingIR {
 #[derivative(Default)]
 pub struct InertialSensor {
     pub valid: bool,
-    pub angle: u32,
-    pub angle_rate: u32,
+    // In 0.01 degree (heading relative to the power-on one)
+    pub angle: i16,
+    // In 0.01 degree/s
+    pub angle_rate: i16,
+    pub angle_deg: f64,
+    pub angle_rad: f64,
+    pub angle_rate_deg_per_sec: f64,
+    pub angle_rate_rad_per_sec: f64,
 }
 #[derive(Debug, Clone, Derivative)]
 #[derivative(Default)]
@@ -75,8 +149,11 @@ pub struct Cliff {
 #[derivative(Default)]
 pub struct Current {
     pub valid: bool,
-    pub left_motor: u32,
-    pub right_motor: u32,
+    // In 10 mA
+    pub left_motor: u8,
+    pub right_motor: u8,
+    pub left_motor_amps: f64,
+    pub right_motor_amps: f64,
 }
 #[derive(Debug, Clone, Derivative)]
 #[derivative(Default)]
@@ -100,15 +177,26 @@ pub struct Gyro {
     pub valid: bool,
     pub frame_id: u32,
     pub followed_data_length: u32,
-    pub x0: u32,
-    pub y0: u32,
-    pub z0: u32,
-    pub x1: u32,
-    pub y1: u32,
-    pub z1: u32,
-    pub x2: u32,
-    pub y2: u32,
-    pub z2: u32,
+    // Oldest first (about 100 Hz, so 2 or 3 samples per packet)
+    pub samples: Vec<GyroSample>,
+    // Averaged over the samples in the packet
+    pub x_rad_per_sec: f64,
+    pub y_rad_per_sec: f64,
+    pub z_rad_per_sec: f64,
+}
+
+#[derive(Debug, Clone, Derivative)]
+#[derivative(Default)]
+pub struct GyroSample {
+    // The frame_id of the packet this sample came in
+    pub frame_id: u32,
+    // In digit (0.00875 degree/s)
+    pub x: i16,
+    pub y: i16,
+    pub z: i16,
+    pub x_rad_per_sec: f64,
+    pub y_rad_per_sec: f64,
+    pub z_rad_per_sec: f64,
 }
 
 #[derive(Debug, Clone, Derivative)]
@@ -139,6 +227,250 @@ pub struct ControllerInfo {
     pub d_gain: u32,
 }
 
+#[derive(Debug, Clone, Derivative)]
+#[derivative(Default)]
+pub struct UnknownSubPayload {
+    pub id: u8,
+    pub data: Vec<u8>,
+}
+
+// A note of a tune
+#[derive(Debug, Clone, Derivative)]
+#[derivative(Default)]
+pub struct TuneNote {
+    // A name (e.g. A4, C#5, Bb3), or empty/R for a rest
+    pub note: String,
+    // How long it lasts (1.0 = a quarter note at the tempo)
+    pub beats: f64,
+}
+
+// Where the tune being played is
+#[derive(Debug, Clone, Derivative)]
+#[derivative(Default)]
+pub struct TuneProgress {
+    pub playing: bool,
+    // From RTTTL (empty for play_tune_command)
+    pub name: String,
+    // Notes (and rests) started so far
+    pub step: u32,
+    pub steps: u32,
+    pub elapsed_ms: f64,
+    // Estimated by the tempo
+    pub duration_ms: f64,
+}
+
+// The sound sequences built in the robot
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Derivative)]
+#[derivative(Default)]
+pub enum SoundSequence {
+    #[derivative(Default)]
+    On = 0,
+    Off = 1,
+    Recharge = 2,
+    Button = 3,
+    Error = 4,
+    CleaningStart = 5,
+    CleaningEnd = 6,
+}
+
+// Which sound sequence the serial thread plays by itself on events (see sound_policy.rs)
+// Each event plays once until it is over (e.g. the bumper is released).
+#[derive(Debug, Clone, Derivative)]
+#[derivative(Default)]
+pub struct EventSoundPolicy {
+    // Nothing is played unless enabled
+    pub enabled: bool,
+    // When the port is opened (or reconnected)
+    #[derivative(Default(value = "true"))]
+    pub on_connected: bool,
+    #[derivative(Default(value = "SoundSequence::On"))]
+    pub connected: SoundSequence,
+    // When any bumper is pressed
+    #[derivative(Default(value = "true"))]
+    pub on_bump: bool,
+    #[derivative(Default(value = "SoundSequence::Button"))]
+    pub bump: SoundSequence,
+    // When the estimated charge drops under low_battery_percentage while discharging
+    #[derivative(Default(value = "true"))]
+    pub on_low_battery: bool,
+    #[derivative(Default(value = "SoundSequence::Recharge"))]
+    pub low_battery: SoundSequence,
+    #[derivative(Default(value = "15.0"))]
+    pub low_battery_percentage: f64,
+    // When the robot gets on the docking station
+    #[derivative(Default(value = "true"))]
+    pub on_docking: bool,
+    #[derivative(Default(value = "SoundSequence::CleaningEnd"))]
+    pub docking: SoundSequence,
+}
+
+// How the serial thread should reopen the port after a read failure
+#[derive(Debug, Clone, Derivative)]
+#[derivative(Default)]
+pub struct ReconnectPolicy {
+    #[derivative(Default(value = "true"))]
+    pub enabled: bool,
+    // The first interval, then multiplied by backoff_multiplier for every failure
+    #[derivative(Default(value = "500"))]
+    pub retry_interval_ms: u32,
+    #[derivative(Default(value = "8000"))]
+    pub max_retry_interval_ms: u32,
+    #[derivative(Default(value = "2.0"))]
+    pub backoff_multiplier: f64,
+    // 0 means retry forever
+    #[derivative(Default(value = "20"))]
+    pub max_attempts: u32,
+    // Find the device by its serial number in case it comes back with another port name
+    #[derivative(Default(value = "true"))]
+    pub relocate_by_serial_number: bool,
+}
+
+// How the serial thread reads the port
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Derivative)]
+#[derivative(Default)]
+pub enum ReadStrategy {
+    // Read once every poll_interval_ms
+    Poll,
+    // Read back to back, each read waits up to read_timeout_ms
+    // Feedbacks are pushed as soon as they are complete.
+    #[derivative(Default)]
+    Blocking,
+}
+
+// How a capture is played by a replay:// port
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Derivative)]
+#[derivative(Default)]
+pub enum ReplayMode {
+    // As fast as it was recorded
+    #[derivative(Default)]
+    RealTime,
+    // Faster (or slower) by the given speed
+    Accelerated,
+    // A packet per replay_step_command
+    SingleStep,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Derivative)]
+#[derivative(Default)]
+pub enum FlowControl {
+    #[derivative(Default)]
+    None,
+    Software,
+    Hardware,
+}
+
+// How to open and read a port
+// Some USB-Serial adapters and bridges need different values than a Kobuki on the desk.
+#[derive(Debug, Clone, Derivative)]
+#[derivative(Default)]
+pub struct ConnectionConfig {
+    #[derivative(Default(value = "115_200"))]
+    pub baud_rate: u32,
+    // Keep it short with ReadStrategy::Blocking so commands don't wait for reads (100 ms at most)
+    #[derivative(Default(value = "5"))]
+    pub read_timeout_ms: u32,
+    pub read_strategy: ReadStrategy,
+    #[derivative(Default(value = "64"))]
+    pub poll_interval_ms: u32,
+    // The size of the read buffer
+    #[derivative(Default(value = "4096"))]
+    pub buffer_size: u32,
+    pub flow_control: FlowControl,
+}
+
+// The encoders and time stamp without wrap-around (since the connection is made)
+#[derive(Debug, Clone, Derivative)]
+#[derivative(Default)]
+pub struct EncoderTicks {
+    pub valid: bool,
+    pub left: i64,
+    pub right: i64,
+    // Since the previous basic sensor data (negative when going backward)
+    pub left_delta: i64,
+    pub right_delta: i64,
+    pub time_stamp_ms: u64,
+    pub dt_ms: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Derivative)]
+#[derivative(Default)]
+pub enum BatteryState {
+    #[derivative(Default)]
+    Discharging,
+    Charging,
+    Charged,
+}
+
+#[derive(Debug, Clone, Derivative)]
+#[derivative(Default)]
+pub struct BatteryStatus {
+    pub valid: bool,
+    // As reported
+    pub volts: f64,
+    // Without the sag by the load
+    pub smoothed_volts: f64,
+    // 4 (standard and extended packs) or 6
+    pub cells: u32,
+    pub percentage: f64,
+    pub state: BatteryState,
+    // Until empty (discharging) or full (charging), 0 if not known yet
+    pub time_remaining_sec: f64,
+}
+
+// How late feedbacks reach Flutter (see latency.rs)
+#[derive(Debug, Clone, Derivative)]
+#[derivative(Default)]
+pub struct LatencyStats {
+    pub samples: u64,
+    pub last_ms: f64,
+    pub mean_ms: f64,
+    pub max_ms: f64,
+}
+
+// How well the bytes from the robot are decoded (per connection)
+#[derive(Debug, Clone, Derivative)]
+#[derivative(Default)]
+pub struct DecoderStats {
+    pub frames_ok: u64,
+    pub crc_failures: u64,
+    pub resyncs: u64,
+    pub bytes_discarded: u64,
+    pub unknown_ids: u64,
+    pub truncated_sub_payloads: u64,
+    pub last_error: String,
+}
+
+// How likely a port is a Kobuki
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Derivative)]
+#[derivative(Default)]
+pub enum MatchConfidence {
+    #[derivative(Default)]
+    Low,
+    Medium,
+    High,
+}
+
+// A USB-Serial port that can be a Kobuki
+#[derive(Debug, Clone, Derivative)]
+#[derivative(Default)]
+pub struct PortDescriptor {
+    pub port_name: String,
+    pub vid: u16,
+    pub pid: u16,
+    pub manufacturer: String,
+    pub product: String,
+    pub serial_number: String,
+    pub confidence: MatchConfidence,
+}
+
+// A Kobuki device that has been attached or detached
+#[derive(Debug, Clone, Derivative)]
+#[derivative(Default)]
+pub struct DeviceEvent {
+    pub attached: bool,
+    pub port: PortDescriptor,
+}
+
 impl Feedback {
     pub fn new() -> Feedback {
         Feedback {
@@ -154,177 +486,364 @@ impl Feedback {
             general_purpose_input: GeneralPurposeInput::default(),
             unique_device_id: UniqueDeviceId::default(),
             controller_info: ControllerInfo::default(),
+            battery: BatteryStatus::default(),
+            encoders: EncoderTicks::default(),
+            unknown_sub_payloads: Vec::new(),
         }
     }
 }
 
-// Should be called ONLY once
-pub fn spawn_turtlebot(sink: StreamSink<String>) -> Result<()> {
-    // The sender will be set as the static in turtlebot.rs
-    let (sender, receiver) = crossbeam::unbounded();
-    set_statics_in_turtlebot(sender);
+// Creates a turtlebot instance and returns its handle.
+// Every command takes the handle, so one dashboard can drive several robots.
+pub fn new_turtlebot() -> Result<u32> {
+    Ok(new_session())
+}
 
-    // The receiver is passed to the turtlebot instance so Flutter can send command to turtlebot
+// Should be called ONLY once per handle
+pub fn spawn_turtlebot(handle: u32, sink: StreamSink<String>) -> Result<()> {
+    // The session of the handle already has the channel and the buffers
     // The sink is passed to the turtlebot instance so it can actively send result to flutter
     // => then flutter should call receive_from_turtlebot to take the data
-    let mut ttb = Turtlebot::new(receiver, sink);
-    ttb.run();
-
-    Ok(())
+    spawn_session(handle, sink)
 }
 
-fn generate_crc(payload: &[u8]) -> u8 {
-    if payload.len() < 5 {
-        return 0;
-    }
-    let payload = payload[5..].to_vec();
-    let mut acc = 0;
-    for (_, c) in payload.iter().enumerate() {
-        acc = acc ^ *c;
-    }
-    acc
+// Closes the port (if opened) and stops the turtlebot instance
+pub fn remove_turtlebot(handle: u32) -> Result<()> {
+    remove_session(handle)
 }
 
 // will be called by other command functions
-fn send_to_turtlebot(mut cmd: Command) -> Result<()> {
-    if cmd.ty != CommandId::SerialControl {
-        let crc = generate_crc(&cmd.payload.clone());
-        cmd.payload.push(crc);
-    }
-    send(cmd);
-    Ok(())
+fn send_to_turtlebot(handle: u32, cmd: Command) -> Result<()> {
+    send(handle, cmd)
+}
+
+// Robot commands are encoded here (with the checksum) and written as they are
+// Flutter gets "written:<id>" or "write_failed:<id>" (e.g. "written:Sound") for each.
+fn send_kobuki_command(handle: u32, command: KobukiCommand) -> Result<()> {
+    let mut cmd = Command::new();
+    cmd.ty = command.id();
+    cmd.payload = command.encode();
+
+    send_to_turtlebot(handle, cmd)
 }
 
 // can be called to receive feedbacks when Flutter side gets notification via stream
-pub fn receive_from_turtlebot() -> Result<Vec<Feedback>> {
-    let feedbacks = receive();
+pub fn receive_from_turtlebot(handle: u32) -> Result<Vec<Feedback>> {
+    let feedbacks = receive(handle);
     match feedbacks {
         Ok(f) => Ok(f),
         Err(_) => Err(anyhow!("What feedback?")),
     }
 }
 
+// can be called to receive device events when Flutter side gets "attached" or "detached" via stream
+pub fn receive_device_events(handle: u32) -> Result<Vec<DeviceEvent>> {
+    let events = receive_devices(handle);
+    match events {
+        Ok(e) => Ok(e),
+        Err(_) => Err(anyhow!("What device?")),
+    }
+}
+
+// Measured since the port is opened
+pub fn latency_command(handle: u32) -> Result<LatencyStats> {
+    latency(handle)
+}
+
+pub fn decoder_stats_command(handle: u32) -> Result<DecoderStats> {
+    decoder_stats(handle)
+}
+
+// The latest battery status (also carried in every Feedback)
+pub fn battery_status_command(handle: u32) -> Result<BatteryStatus> {
+    battery_status(handle)
+}
+
 pub fn search_port_command() -> Result<Vec<String>> {
     let ports = available_tutlebots();
     match ports {
-        Ok(p) => {
-            if p.len() > 0 {
-                return Ok(p);
-            } else {
-                return Err(anyhow!("What port?"));
-            }
-        }
-        Err(_) => Err(anyhow!("What port?")),
+        Ok(p) if !p.is_empty() => Ok(p),
+        _ => Err(anyhow!("What port?")),
     }
 }
 
-pub fn open_port_command(serial_port: String) -> Result<()> {
+// Same as search_port_command, but with the USB information of each port
+pub fn search_port_descriptors_command() -> Result<Vec<PortDescriptor>> {
+    let ports = available_turtlebot_ports();
+    match ports {
+        Ok(p) if !p.is_empty() => Ok(p),
+        _ => Err(anyhow!("What port?")),
+    }
+}
+
+// The result comes to the sink as "opened" or "open_failed"
+pub fn open_port_command(handle: u32, serial_port: String) -> Result<()> {
+    open_port_with_config_command(handle, serial_port, ConnectionConfig::default())
+}
+
+pub fn open_port_with_config_command(
+    handle: u32,
+    serial_port: String,
+    config: ConnectionConfig,
+) -> Result<()> {
+    check_connection_config(&config)?;
     let mut cmd = Command::new();
     cmd.ty = CommandId::SerialControl;
     cmd.serial_command = "open".to_string();
     cmd.serial_port_name = serial_port;
+    cmd.connection_config = config;
 
-    send_to_turtlebot(cmd);
+    send_to_turtlebot(handle, cmd)?;
     Ok(())
 }
 
-pub fn close_port_command() -> Result<()> {
+// A zero timeout/interval would make the serial thread spin
+// Commands wait while the serial thread blocks on a read
+const MAX_BLOCKING_READ_TIMEOUT_MS: u32 = 100;
+
+fn check_connection_config(config: &ConnectionConfig) -> Result<()> {
+    if config.baud_rate == 0 {
+        return Err(anyhow!("What baud rate? 0"));
+    }
+    if config.read_strategy == ReadStrategy::Blocking
+        && !(1..=MAX_BLOCKING_READ_TIMEOUT_MS).contains(&config.read_timeout_ms)
+    {
+        return Err(anyhow!(
+            "What read timeout? {} ms (1 ~ {} ms with Blocking)",
+            config.read_timeout_ms,
+            MAX_BLOCKING_READ_TIMEOUT_MS
+        ));
+    }
+    if config.read_strategy == ReadStrategy::Poll && config.poll_interval_ms == 0 {
+        return Err(anyhow!("What poll interval? 0 ms"));
+    }
+    if config.buffer_size == 0 {
+        return Err(anyhow!("What buffer size? 0"));
+    }
+    Ok(())
+}
+
+fn check_reconnect_policy(policy: &ReconnectPolicy) -> Result<()> {
+    // NaN and infinity fall out of the range too
+    if !(1.0..=16.0).contains(&policy.backoff_multiplier) {
+        return Err(anyhow!(
+            "What backoff multiplier? {} (1.0 ~ 16.0)",
+            policy.backoff_multiplier
+        ));
+    }
+    // 0 would retry in a tight loop
+    if policy.retry_interval_ms == 0 {
+        return Err(anyhow!("What retry interval? 0 ms"));
+    }
+    if policy.max_retry_interval_ms < policy.retry_interval_ms {
+        return Err(anyhow!(
+            "What max retry interval? {} ms (< retry interval {} ms)",
+            policy.max_retry_interval_ms,
+            policy.retry_interval_ms
+        ));
+    }
+    Ok(())
+}
+
+pub fn close_port_command(handle: u32) -> Result<()> {
     let mut cmd = Command::new();
     cmd.ty = CommandId::SerialControl;
     cmd.serial_command = "close".to_string();
 
-    send_to_turtlebot(cmd);
+    send_to_turtlebot(handle, cmd)?;
+    Ok(())
+}
+
+// Applied to the open port right away and to the next ones
+pub fn set_event_sound_policy_command(handle: u32, policy: EventSoundPolicy) -> Result<()> {
+    if !(0.0..=100.0).contains(&policy.low_battery_percentage) {
+        return Err(anyhow!(
+            "What low battery percentage? {}",
+            policy.low_battery_percentage
+        ));
+    }
+    let mut cmd = Command::new();
+    cmd.ty = CommandId::SerialControl;
+    cmd.serial_command = "event_sound_policy".to_string();
+    cmd.event_sound_policy = policy;
+
+    send_to_turtlebot(handle, cmd)?;
+    Ok(())
+}
+
+// Will be applied from the next open_port_command
+pub fn set_reconnect_policy_command(handle: u32, policy: ReconnectPolicy) -> Result<()> {
+    check_reconnect_policy(&policy)?;
+
+    let mut cmd = Command::new();
+    cmd.ty = CommandId::SerialControl;
+    cmd.serial_command = "reconnect_policy".to_string();
+    cmd.reconnect_policy = policy;
+
+    send_to_turtlebot(handle, cmd)?;
     Ok(())
 }
 
-pub fn base_control_command(speed: u16, radius: u16) -> Result<()> {
-    let mut payload: Vec<u8> = Vec::new();
-    payload.push(0xaa);
-    payload.push(0x55);
-    payload.push(CMD_LEN_BASE_CONTROL);
-    payload.push(CommandId::BaseControl as u8);
-    payload.push(CMD_SIZE_BASE_CONTROL);
-    payload.push((speed & 0xff) as u8);
-    payload.push((speed & 0xff00).shr(8) as u8);
-    payload.push((radius & 0xff) as u8);
-    payload.push((radius & 0xff00).shr(8) as u8);
+// Records every byte read from/written to the open port (see capture.rs)
+// An empty path records to a timestamped file in the temp directory.
+// Returns the path of the capture file.
+pub fn start_capture_command(handle: u32, path: String) -> Result<String> {
+    let path = if path.is_empty() {
+        default_capture_path()
+    } else {
+        path
+    };
+    let mut cmd = Command::new();
+    cmd.ty = CommandId::SerialControl;
+    cmd.serial_command = "start_capture".to_string();
+    cmd.capture_path = path.clone();
 
+    send_to_turtlebot(handle, cmd)?;
+    Ok(path)
+}
+
+pub fn stop_capture_command(handle: u32) -> Result<()> {
     let mut cmd = Command::new();
-    cmd.ty = CommandId::BaseControl;
-    cmd.payload = payload;
+    cmd.ty = CommandId::SerialControl;
+    cmd.serial_command = "stop_capture".to_string();
 
-    send_to_turtlebot(cmd);
+    send_to_turtlebot(handle, cmd)?;
     Ok(())
 }
 
-pub fn sound_command(freq: u8, amp: u8, duration: u8) -> Result<()> {
-    // To avoid divide by zero
-    if freq == 0 || amp == 0 || duration == 0 {
-        return Err(anyhow!(""));
+// A capture file can be opened like a port with replay:///path/to/capture.log
+// Then these control the playback while it is open.
+// The speed is only used by ReplayMode::Accelerated (e.g. 4.0 for 4x).
+pub fn replay_mode_command(handle: u32, mode: ReplayMode, speed: f64) -> Result<()> {
+    if mode == ReplayMode::Accelerated && !(speed.is_finite() && speed > 0.0) {
+        return Err(anyhow!("What speed? {}", speed));
     }
+    send_replay_control(handle, ReplayControl::Mode(mode, speed))
+}
 
-    let mut payload: Vec<u8> = Vec::new();
-    let tmp: u16 = (1 / (freq * amp)) as u16;
+pub fn replay_pause_command(handle: u32, paused: bool) -> Result<()> {
+    send_replay_control(handle, ReplayControl::Pause(paused))
+}
 
-    let mut payload: Vec<u8> = Vec::new();
-    payload.push(0xaa);
-    payload.push(0x55);
-    payload.push(CMD_LEN_SOUND);
-    payload.push(CommandId::Sound as u8);
-    payload.push(CMD_SIZE_SOUND);
-    payload.push((tmp & 0xff) as u8);
-    payload.push((tmp & 0xff00).shr(8) as u8);
-    payload.push(duration);
+// The position is from the beginning of the capture
+pub fn replay_seek_command(handle: u32, position_ms: u64) -> Result<()> {
+    send_replay_control(handle, ReplayControl::Seek(position_ms))
+}
 
+pub fn replay_step_command(handle: u32) -> Result<()> {
+    send_replay_control(handle, ReplayControl::Step)
+}
+
+fn send_replay_control(handle: u32, control: ReplayControl) -> Result<()> {
     let mut cmd = Command::new();
-    cmd.ty = CommandId::Sound;
-    cmd.payload = payload;
+    cmd.ty = CommandId::SerialControl;
+    cmd.serial_command = "replay".to_string();
+    cmd.replay_control = Some(control);
+
+    send_to_turtlebot(handle, cmd)?;
+    Ok(())
+}
+
+// Drives by the linear (m/s, forward +) and angular (rad/s, counterclockwise +) velocity
+// The velocities are clamped to the limits of the robot (0.7 m/s, 180 degree/s).
+pub fn drive_command(handle: u32, linear: f64, angular: f64) -> Result<()> {
+    send_kobuki_command(handle, KobukiCommand::drive(linear, angular))?;
+    Ok(())
+}
+
+// The raw form of drive_command
+// - speed: mm/s (negative: backward)
+// - radius: mm (0: straight, 1: turn in place, positive: to the left)
+pub fn base_control_command(handle: u32, speed: i16, radius: i16) -> Result<()> {
+    let command = KobukiCommand::BaseControl { speed, radius };
 
-    send_to_turtlebot(cmd);
+    send_kobuki_command(handle, command)?;
     Ok(())
 }
 
-pub fn sound_sequence_command(seq: u8) -> Result<()> {
-    let mut payload: Vec<u8> = Vec::new();
-    payload.push(0xaa);
-    payload.push(0x55);
-    payload.push(CMD_LEN_SOUND_SEQUENCE);
-    payload.push(CommandId::SoundSequence as u8);
-    payload.push(CMD_SIZE_SOUND_SEQUENCE);
-    payload.push(seq);
+// Plays a frequency (Hz) for up to 255 ms
+pub fn sound_command(handle: u32, frequency: f64, duration_ms: u8) -> Result<()> {
+    send_kobuki_command(handle, KobukiCommand::sound(frequency, duration_ms)?)?;
+    Ok(())
+}
 
+// Plays a note by name (e.g. A4, C#5, Bb3) for up to 255 ms
+pub fn note_command(handle: u32, note: String, duration_ms: u8) -> Result<()> {
+    let frequency = note_frequency(&note)?;
+    send_kobuki_command(handle, KobukiCommand::sound(frequency, duration_ms)?)?;
+    Ok(())
+}
+
+// Plays the notes one after another (replacing what is playing)
+// Flutter gets "tune_started", "tune_progress" at every note and "tune_finished" at the end.
+pub fn play_tune_command(handle: u32, notes: Vec<TuneNote>, tempo_bpm: f64) -> Result<()> {
     let mut cmd = Command::new();
-    cmd.ty = CommandId::SoundSequence;
-    cmd.payload = payload;
+    cmd.ty = CommandId::SerialControl;
+    cmd.serial_command = "play_tune".to_string();
+    cmd.tune = Some(Tune::from_notes(&notes, tempo_bpm)?);
 
-    send_to_turtlebot(cmd);
+    send_to_turtlebot(handle, cmd)?;
     Ok(())
 }
 
-pub fn request_extra_command(hw_ver: bool, fw_ver: bool, udid: bool) -> Result<()> {
-    let mut tmp: u8 = 0;
-    tmp |= hw_ver as u8;
-    tmp |= (fw_ver as u8).shl(1);
-    tmp |= (udid as u8).shl(7);
+// Plays an RTTTL ringtone (e.g. "Beep:d=8,o=5,b=120:c,e,g,2c6") like play_tune_command
+pub fn play_rtttl_command(handle: u32, rtttl: String) -> Result<()> {
+    let mut cmd = Command::new();
+    cmd.ty = CommandId::SerialControl;
+    cmd.serial_command = "play_tune".to_string();
+    cmd.tune = Some(parse_rtttl(&rtttl)?);
+
+    send_to_turtlebot(handle, cmd)?;
+    Ok(())
+}
+
+// The latest progress of the tune (updated at every note)
+pub fn tune_progress_command(handle: u32) -> Result<TuneProgress> {
+    tune_progress(handle)
+}
+
+// Applied from the next note of the tune being played
+pub fn set_tune_tempo_command(handle: u32, tempo_bpm: f64) -> Result<()> {
+    check_tempo(tempo_bpm)?;
+    let mut cmd = Command::new();
+    cmd.ty = CommandId::SerialControl;
+    cmd.serial_command = "tune_tempo".to_string();
+    cmd.tempo_bpm = tempo_bpm;
 
-    let mut payload: Vec<u8> = Vec::new();
-    payload.push(0xaa);
-    payload.push(0x55);
-    payload.push(CMD_LEN_REQUEST_EXTRA);
-    payload.push(CommandId::RequestExtra as u8);
-    payload.push(CMD_SIZE_REQUEST_EXTRA);
-    payload.push(tmp);
+    send_to_turtlebot(handle, cmd)?;
+    Ok(())
+}
 
+// Cancels the tune being played (Flutter gets "tune_stopped")
+pub fn stop_tune_command(handle: u32) -> Result<()> {
     let mut cmd = Command::new();
-    cmd.ty = CommandId::RequestExtra;
-    cmd.payload = payload;
+    cmd.ty = CommandId::SerialControl;
+    cmd.serial_command = "stop_tune".to_string();
 
-    send_to_turtlebot(cmd);
+    send_to_turtlebot(handle, cmd)?;
+    Ok(())
+}
+
+// Plays a sound sequence built in the robot
+pub fn sound_sequence_command(handle: u32, seq: SoundSequence) -> Result<()> {
+    send_kobuki_command(handle, KobukiCommand::SoundSequence(seq))?;
+    Ok(())
+}
+
+pub fn request_extra_command(handle: u32, hw_ver: bool, fw_ver: bool, udid: bool) -> Result<()> {
+    let command = KobukiCommand::RequestExtra {
+        hardware_version: hw_ver,
+        firmware_version: fw_ver,
+        unique_device_id: udid,
+    };
 
+    send_kobuki_command(handle, command)?;
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn general_purpose_output_command(
+    handle: u32,
     d_out_ch0: bool,
     d_out_ch1: bool,
     d_out_ch2: bool,
@@ -338,91 +857,55 @@ pub fn general_purpose_output_command(
     green_led1: bool,
     green_led2: bool,
 ) -> Result<()> {
-    let mut tmp0: u8 = 0;
-    tmp0 |= d_out_ch0 as u8;
-    tmp0 |= (d_out_ch1 as u8).shl(1);
-    tmp0 |= (d_out_ch2 as u8).shl(2);
-    tmp0 |= (d_out_ch3 as u8).shl(3);
-    tmp0 |= (power_3v3 as u8).shl(4);
-    tmp0 |= (power_5v0 as u8).shl(5);
-    tmp0 |= (power_12v5a as u8).shl(6);
-    tmp0 |= (power_12v1a5 as u8).shl(7);
-    let mut tmp1: u8 = 0;
-    tmp1 |= red_led1 as u8;
-    tmp1 |= (green_led1 as u8).shl(1);
-    tmp1 |= (red_led2 as u8).shl(2);
-    tmp1 |= (green_led2 as u8).shl(3);
-
-    let mut payload: Vec<u8> = Vec::new();
-    payload.push(0xaa);
-    payload.push(0x55);
-    payload.push(CMD_LEN_REQUEST_EXTRA);
-    payload.push(CommandId::RequestExtra as u8);
-    payload.push(CMD_SIZE_REQUEST_EXTRA);
-    payload.push(tmp0);
-    payload.push(tmp1);
-
-    let mut cmd = Command::new();
-    cmd.ty = CommandId::GeneralPurposeOutput;
-    cmd.payload = payload;
-
-    send_to_turtlebot(cmd);
-
+    let mut tmp: u16 = 0;
+    tmp |= d_out_ch0 as u16;
+    tmp |= (d_out_ch1 as u16).shl(1);
+    tmp |= (d_out_ch2 as u16).shl(2);
+    tmp |= (d_out_ch3 as u16).shl(3);
+    tmp |= (power_3v3 as u16).shl(4);
+    tmp |= (power_5v0 as u16).shl(5);
+    tmp |= (power_12v5a as u16).shl(6);
+    tmp |= (power_12v1a5 as u16).shl(7);
+    tmp |= (red_led1 as u16).shl(8);
+    tmp |= (green_led1 as u16).shl(9);
+    tmp |= (red_led2 as u16).shl(10);
+    tmp |= (green_led2 as u16).shl(11);
+
+    send_kobuki_command(handle, KobukiCommand::GeneralPurposeOutput(tmp))?;
     Ok(())
 }
 
-pub fn set_controller_gain_command(is_user_configured: bool, p: u32, i: f32, d: u32) -> Result<()> {
-    let mut pp = if p == 0 { 1000 } else { p * 1000 };
-    let mut ii = if i < 0.1 || i > 32000.0 {
+pub fn set_controller_gain_command(
+    handle: u32,
+    is_user_configured: bool,
+    p: u32,
+    i: f32,
+    d: u32,
+) -> Result<()> {
+    let gain = |g: u32| {
+        g.checked_mul(1000)
+            .ok_or_else(|| anyhow!("What gain? {}", g))
+    };
+    let pp = if p == 0 { 1000 } else { gain(p)? };
+    let ii = if !(0.1..=32000.0).contains(&i) {
         (0.1 * 1000.0) as u32
     } else {
         (i * 1000.0) as u32
     };
-    let mut dd = if d == 0 { 2 * 1000 } else { d * 1000 };
-
-    let mut payload: Vec<u8> = Vec::new();
-    payload.push(0xaa);
-    payload.push(0x55);
-    payload.push(CMD_LEN_SET_CONTROLLER_GAIN);
-    payload.push(CommandId::SetControllerGain as u8);
-    payload.push(CMD_SIZE_SET_CONTROLLER_GAIN);
-    payload.push(is_user_configured as u8);
-    payload.push((pp & 0x000000ff) as u8);
-    payload.push((pp & 0x0000ff00).shr(8) as u8);
-    payload.push((pp & 0x00ff0000).shr(16) as u8);
-    payload.push((pp & 0xff000000).shr(24) as u8);
-    payload.push((ii & 0x000000ff) as u8);
-    payload.push((ii & 0x0000ff00).shr(8) as u8);
-    payload.push((ii & 0x00ff0000).shr(16) as u8);
-    payload.push((ii & 0xff000000).shr(24) as u8);
-    payload.push((dd & 0x000000ff) as u8);
-    payload.push((dd & 0x0000ff00).shr(8) as u8);
-    payload.push((dd & 0x00ff0000).shr(16) as u8);
-    payload.push((dd & 0xff000000).shr(24) as u8);
+    let dd = if d == 0 { 2 * 1000 } else { gain(d)? };
 
-    let mut cmd = Command::new();
-    cmd.ty = CommandId::SetControllerGain;
-    cmd.payload = payload;
-
-    send_to_turtlebot(cmd);
+    let command = KobukiCommand::SetControllerGain {
+        user_configured: is_user_configured,
+        p: pp,
+        i: ii,
+        d: dd,
+    };
 
+    send_kobuki_command(handle, command)?;
     Ok(())
 }
 
-pub fn get_controller_gain() -> Result<()> {
-    let mut payload: Vec<u8> = Vec::new();
-    payload.push(0xaa);
-    payload.push(0x55);
-    payload.push(CMD_LEN_GET_CONTROLLER_GAIN);
-    payload.push(CommandId::GetControllerGain as u8);
-    payload.push(CMD_SIZE_GET_CONTROLLER_GAIN);
-    payload.push(0xff);
-
-    let mut cmd = Command::new();
-    cmd.ty = CommandId::GetControllerGain;
-    cmd.payload = payload;
-
-    send_to_turtlebot(cmd);
-
+pub fn get_controller_gain(handle: u32) -> Result<()> {
+    send_kobuki_command(handle, KobukiCommand::GetControllerGain)?;
     Ok(())
 }
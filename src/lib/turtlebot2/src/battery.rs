@@ -0,0 +1,249 @@
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+use crate::api::{BasicSensor, BatteryState, BatteryStatus, ChargerState};
+
+// Open circuit voltage of a Li-ion cell => state of charge (%)
+const CELL_CURVE: [(f64, f64); 11] = [
+    (3.00, 0.0),
+    (3.30, 5.0),
+    (3.50, 10.0),
+    (3.60, 20.0),
+    (3.70, 40.0),
+    (3.75, 50.0),
+    (3.80, 60.0),
+    (3.90, 75.0),
+    (4.00, 85.0),
+    (4.10, 95.0),
+    (4.20, 100.0),
+];
+// A 4S pack never goes above this (4.2 V x 4 + margin), so a higher voltage means 6S
+const SIX_CELL_THRESHOLD: f64 = 17.5;
+// Smoothing of the load sag (e.g. the motors start)
+const SMOOTHING: Duration = Duration::from_secs(5);
+// The state of charge changes slowly, so the rate needs a long window
+const RATE_WINDOW: Duration = Duration::from_secs(120);
+const RATE_MIN_SPAN: Duration = Duration::from_secs(30);
+
+// BatteryModel turns the raw battery byte and the charger flags into a BatteryStatus.
+// Should be fed every basic sensor data of a connection in order.
+pub struct BatteryModel {
+    smoothed: Option<f64>,
+    last: Option<Instant>,
+    state: BatteryState,
+    // (when, state of charge) to estimate the time remaining
+    history: VecDeque<(Instant, f64)>,
+}
+
+impl BatteryModel {
+    pub fn new() -> BatteryModel {
+        BatteryModel {
+            smoothed: None,
+            last: None,
+            state: BatteryState::default(),
+            history: VecDeque::new(),
+        }
+    }
+
+    pub fn update(&mut self, sensor: &BasicSensor, now: Instant) -> BatteryStatus {
+        let volts = sensor.battery_volts;
+        let state = match sensor.charger_state {
+            ChargerState::Discharging => BatteryState::Discharging,
+            ChargerState::DockingCharged | ChargerState::AdapterCharged => BatteryState::Charged,
+            ChargerState::DockingCharging | ChargerState::AdapterCharging => BatteryState::Charging,
+        };
+        // The voltage jumps when a charger is plugged/unplugged, so start over
+        if state != self.state {
+            self.state = state;
+            self.smoothed = None;
+            self.history.clear();
+        }
+
+        // Exponential moving average by the elapsed time
+        let smoothed = match (self.smoothed, self.last) {
+            (Some(s), Some(last)) => {
+                let dt = now.saturating_duration_since(last).as_secs_f64();
+                let alpha = (dt / SMOOTHING.as_secs_f64()).min(1.0);
+                s + (volts - s) * alpha
+            }
+            _ => volts,
+        };
+        self.smoothed = Some(smoothed);
+        self.last = Some(now);
+
+        let cells = if smoothed > SIX_CELL_THRESHOLD { 6 } else { 4 };
+        let percentage = match state {
+            BatteryState::Charged => 100.0,
+            _ => state_of_charge(smoothed / cells as f64),
+        };
+
+        self.history.push_back((now, percentage));
+        while let Some((t, _)) = self.history.front() {
+            if now.saturating_duration_since(*t) <= RATE_WINDOW {
+                break;
+            }
+            self.history.pop_front();
+        }
+
+        BatteryStatus {
+            valid: true,
+            volts,
+            smoothed_volts: smoothed,
+            cells,
+            percentage,
+            state,
+            time_remaining_sec: self.time_remaining(state, percentage),
+        }
+    }
+
+    // Until empty (discharging) or full (charging), 0 if not known yet
+    fn time_remaining(&self, state: BatteryState, percentage: f64) -> f64 {
+        let (first, last) = match (self.history.front(), self.history.back()) {
+            (Some(f), Some(l)) => (f, l),
+            _ => return 0.0,
+        };
+        let span = last.0.saturating_duration_since(first.0);
+        if span < RATE_MIN_SPAN {
+            return 0.0;
+        }
+        // % per second
+        let rate = (last.1 - first.1) / span.as_secs_f64();
+        match state {
+            BatteryState::Discharging if rate < 0.0 => percentage / -rate,
+            BatteryState::Charging if rate > 0.0 => (100.0 - percentage) / rate,
+            _ => 0.0,
+        }
+    }
+}
+
+// Linear between the points of the curve
+fn state_of_charge(cell_volts: f64) -> f64 {
+    let (first, last) = (CELL_CURVE[0], CELL_CURVE[CELL_CURVE.len() - 1]);
+    if cell_volts <= first.0 {
+        return first.1;
+    }
+    if cell_volts >= last.0 {
+        return last.1;
+    }
+    for w in CELL_CURVE.windows(2) {
+        let ((v0, p0), (v1, p1)) = (w[0], w[1]);
+        if cell_volts <= v1 {
+            return p0 + (p1 - p0) * (cell_volts - v0) / (v1 - v0);
+        }
+    }
+    last.1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sensor(volts: f64, charger_state: ChargerState) -> BasicSensor {
+        BasicSensor {
+            valid: true,
+            battery_volts: volts,
+            charger_state,
+            ..Default::default()
+        }
+    }
+
+    fn assert_near(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-6, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn state_of_charge_curve() {
+        let table = [
+            (2.50, 0.0),
+            (3.00, 0.0),
+            (3.15, 2.5),
+            (3.30, 5.0),
+            (3.65, 30.0),
+            (3.75, 50.0),
+            (3.85, 67.5),
+            (4.20, 100.0),
+            (4.50, 100.0),
+        ];
+        for (volts, percentage) in table {
+            assert_near(state_of_charge(volts), percentage);
+        }
+    }
+
+    #[test]
+    fn cells() {
+        let table = [(12.0, 4), (16.8, 4), (17.5, 4), (17.6, 6), (25.2, 6)];
+        for (volts, cells) in table {
+            let mut model = BatteryModel::new();
+            let b = model.update(&sensor(volts, ChargerState::Discharging), Instant::now());
+            assert_eq!(b.cells, cells, "{} V", volts);
+        }
+        // 3.75 V per cell either way
+        let mut model = BatteryModel::new();
+        let b = model.update(&sensor(22.5, ChargerState::Discharging), Instant::now());
+        assert_near(b.percentage, 50.0);
+    }
+
+    #[test]
+    fn smoothing() {
+        let mut model = BatteryModel::new();
+        let t = Instant::now();
+        model.update(&sensor(16.0, ChargerState::Discharging), t);
+        // A fifth of the way after a second
+        let b = model.update(
+            &sensor(15.0, ChargerState::Discharging),
+            t + Duration::from_secs(1),
+        );
+        assert_near(b.volts, 15.0);
+        assert_near(b.smoothed_volts, 15.8);
+        // All the way after the smoothing time
+        let b = model.update(
+            &sensor(15.0, ChargerState::Discharging),
+            t + Duration::from_secs(10),
+        );
+        assert_near(b.smoothed_volts, 15.0);
+    }
+
+    #[test]
+    fn reset_on_charger_change() {
+        let mut model = BatteryModel::new();
+        let t = Instant::now();
+        model.update(&sensor(15.0, ChargerState::Discharging), t);
+        let b = model.update(
+            &sensor(16.6, ChargerState::AdapterCharging),
+            t + Duration::from_secs(1),
+        );
+        assert_eq!(b.state, BatteryState::Charging);
+        assert_near(b.smoothed_volts, 16.6);
+
+        let b = model.update(
+            &sensor(16.6, ChargerState::DockingCharged),
+            t + Duration::from_secs(2),
+        );
+        assert_eq!(b.state, BatteryState::Charged);
+        assert_near(b.percentage, 100.0);
+    }
+
+    #[test]
+    fn time_remaining() {
+        let mut model = BatteryModel::new();
+        let t = Instant::now();
+        // 50% => 40% in a minute (3.75 => 3.70 V per cell), a sample per 10 s
+        let mut b = BatteryStatus::default();
+        for i in 0..=6 {
+            let volts = 15.0 - 0.2 * i as f64 / 6.0;
+            b = model.update(
+                &sensor(volts, ChargerState::Discharging),
+                t + Duration::from_secs(10 * i),
+            );
+            // Not known until the rate window has 30 s
+            if i < 3 {
+                assert_eq!(b.time_remaining_sec, 0.0);
+            }
+        }
+        assert_near(b.percentage, 40.0);
+        // 10% per minute
+        assert!((b.time_remaining_sec - 240.0).abs() < 1e-3);
+    }
+}
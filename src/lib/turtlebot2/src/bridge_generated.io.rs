@@ -0,0 +1,532 @@
+use super::*;
+// Section: wire functions
+
+#[no_mangle]
+pub extern "C" fn wire_new_turtlebot(port_: i64) {
+    wire_new_turtlebot_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_spawn_turtlebot(port_: i64, handle: u32) {
+    wire_spawn_turtlebot_impl(port_, handle)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_remove_turtlebot(port_: i64, handle: u32) {
+    wire_remove_turtlebot_impl(port_, handle)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_receive_from_turtlebot(port_: i64, handle: u32) {
+    wire_receive_from_turtlebot_impl(port_, handle)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_receive_device_events(port_: i64, handle: u32) {
+    wire_receive_device_events_impl(port_, handle)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_latency_command(port_: i64, handle: u32) {
+    wire_latency_command_impl(port_, handle)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_decoder_stats_command(port_: i64, handle: u32) {
+    wire_decoder_stats_command_impl(port_, handle)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_battery_status_command(port_: i64, handle: u32) {
+    wire_battery_status_command_impl(port_, handle)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_search_port_command(port_: i64) {
+    wire_search_port_command_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_search_port_descriptors_command(port_: i64) {
+    wire_search_port_descriptors_command_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_open_port_command(
+    port_: i64,
+    handle: u32,
+    serial_port: *mut wire_uint_8_list,
+) {
+    wire_open_port_command_impl(port_, handle, serial_port)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_open_port_with_config_command(
+    port_: i64,
+    handle: u32,
+    serial_port: *mut wire_uint_8_list,
+    config: *mut wire_ConnectionConfig,
+) {
+    wire_open_port_with_config_command_impl(port_, handle, serial_port, config)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_close_port_command(port_: i64, handle: u32) {
+    wire_close_port_command_impl(port_, handle)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_set_event_sound_policy_command(
+    port_: i64,
+    handle: u32,
+    policy: *mut wire_EventSoundPolicy,
+) {
+    wire_set_event_sound_policy_command_impl(port_, handle, policy)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_set_reconnect_policy_command(
+    port_: i64,
+    handle: u32,
+    policy: *mut wire_ReconnectPolicy,
+) {
+    wire_set_reconnect_policy_command_impl(port_, handle, policy)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_start_capture_command(port_: i64, handle: u32, path: *mut wire_uint_8_list) {
+    wire_start_capture_command_impl(port_, handle, path)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_stop_capture_command(port_: i64, handle: u32) {
+    wire_stop_capture_command_impl(port_, handle)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_replay_mode_command(port_: i64, handle: u32, mode: i32, speed: f64) {
+    wire_replay_mode_command_impl(port_, handle, mode, speed)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_replay_pause_command(port_: i64, handle: u32, paused: bool) {
+    wire_replay_pause_command_impl(port_, handle, paused)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_replay_seek_command(port_: i64, handle: u32, position_ms: u64) {
+    wire_replay_seek_command_impl(port_, handle, position_ms)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_replay_step_command(port_: i64, handle: u32) {
+    wire_replay_step_command_impl(port_, handle)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_drive_command(port_: i64, handle: u32, linear: f64, angular: f64) {
+    wire_drive_command_impl(port_, handle, linear, angular)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_base_control_command(port_: i64, handle: u32, speed: i16, radius: i16) {
+    wire_base_control_command_impl(port_, handle, speed, radius)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_sound_command(port_: i64, handle: u32, frequency: f64, duration_ms: u8) {
+    wire_sound_command_impl(port_, handle, frequency, duration_ms)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_note_command(
+    port_: i64,
+    handle: u32,
+    note: *mut wire_uint_8_list,
+    duration_ms: u8,
+) {
+    wire_note_command_impl(port_, handle, note, duration_ms)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_play_tune_command(
+    port_: i64,
+    handle: u32,
+    notes: *mut wire_list_tune_note,
+    tempo_bpm: f64,
+) {
+    wire_play_tune_command_impl(port_, handle, notes, tempo_bpm)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_play_rtttl_command(port_: i64, handle: u32, rtttl: *mut wire_uint_8_list) {
+    wire_play_rtttl_command_impl(port_, handle, rtttl)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_tune_progress_command(port_: i64, handle: u32) {
+    wire_tune_progress_command_impl(port_, handle)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_set_tune_tempo_command(port_: i64, handle: u32, tempo_bpm: f64) {
+    wire_set_tune_tempo_command_impl(port_, handle, tempo_bpm)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_stop_tune_command(port_: i64, handle: u32) {
+    wire_stop_tune_command_impl(port_, handle)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_sound_sequence_command(port_: i64, handle: u32, seq: i32) {
+    wire_sound_sequence_command_impl(port_, handle, seq)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_request_extra_command(
+    port_: i64,
+    handle: u32,
+    hw_ver: bool,
+    fw_ver: bool,
+    udid: bool,
+) {
+    wire_request_extra_command_impl(port_, handle, hw_ver, fw_ver, udid)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_general_purpose_output_command(
+    port_: i64,
+    handle: u32,
+    d_out_ch0: bool,
+    d_out_ch1: bool,
+    d_out_ch2: bool,
+    d_out_ch3: bool,
+    power_3v3: bool,
+    power_5v0: bool,
+    power_12v5a: bool,
+    power_12v1a5: bool,
+    red_led1: bool,
+    red_led2: bool,
+    green_led1: bool,
+    green_led2: bool,
+) {
+    wire_general_purpose_output_command_impl(
+        port_,
+        handle,
+        d_out_ch0,
+        d_out_ch1,
+        d_out_ch2,
+        d_out_ch3,
+        power_3v3,
+        power_5v0,
+        power_12v5a,
+        power_12v1a5,
+        red_led1,
+        red_led2,
+        green_led1,
+        green_led2,
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_set_controller_gain_command(
+    port_: i64,
+    handle: u32,
+    is_user_configured: bool,
+    p: u32,
+    i: f32,
+    d: u32,
+) {
+    wire_set_controller_gain_command_impl(port_, handle, is_user_configured, p, i, d)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_get_controller_gain(port_: i64, handle: u32) {
+    wire_get_controller_gain_impl(port_, handle)
+}
+
+// Section: allocate functions
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_connection_config_0() -> *mut wire_ConnectionConfig {
+    support::new_leak_box_ptr(wire_ConnectionConfig::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_event_sound_policy_0() -> *mut wire_EventSoundPolicy {
+    support::new_leak_box_ptr(wire_EventSoundPolicy::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_reconnect_policy_0() -> *mut wire_ReconnectPolicy {
+    support::new_leak_box_ptr(wire_ReconnectPolicy::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_list_tune_note_0(len: i32) -> *mut wire_list_tune_note {
+    let wrap = wire_list_tune_note {
+        ptr: support::new_leak_vec_ptr(<wire_TuneNote>::new_with_null_ptr(), len),
+        len,
+    };
+    support::new_leak_box_ptr(wrap)
+}
+
+#[no_mangle]
+pub extern "C" fn new_uint_8_list_0(len: i32) -> *mut wire_uint_8_list {
+    let ans = wire_uint_8_list {
+        ptr: support::new_leak_vec_ptr(Default::default(), len),
+        len,
+    };
+    support::new_leak_box_ptr(ans)
+}
+
+// Section: related functions
+
+// Section: impl Wire2Api
+
+impl Wire2Api<ConnectionConfig> for *mut wire_ConnectionConfig {
+    fn wire2api(self) -> ConnectionConfig {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<ConnectionConfig>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<EventSoundPolicy> for *mut wire_EventSoundPolicy {
+    fn wire2api(self) -> EventSoundPolicy {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<EventSoundPolicy>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<ReconnectPolicy> for *mut wire_ReconnectPolicy {
+    fn wire2api(self) -> ReconnectPolicy {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<ReconnectPolicy>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<ConnectionConfig> for wire_ConnectionConfig {
+    fn wire2api(self) -> ConnectionConfig {
+        ConnectionConfig {
+            baud_rate: self.baud_rate.wire2api(),
+            read_timeout_ms: self.read_timeout_ms.wire2api(),
+            read_strategy: self.read_strategy.wire2api(),
+            poll_interval_ms: self.poll_interval_ms.wire2api(),
+            buffer_size: self.buffer_size.wire2api(),
+            flow_control: self.flow_control.wire2api(),
+        }
+    }
+}
+impl Wire2Api<EventSoundPolicy> for wire_EventSoundPolicy {
+    fn wire2api(self) -> EventSoundPolicy {
+        EventSoundPolicy {
+            enabled: self.enabled.wire2api(),
+            on_connected: self.on_connected.wire2api(),
+            connected: self.connected.wire2api(),
+            on_bump: self.on_bump.wire2api(),
+            bump: self.bump.wire2api(),
+            on_low_battery: self.on_low_battery.wire2api(),
+            low_battery: self.low_battery.wire2api(),
+            low_battery_percentage: self.low_battery_percentage.wire2api(),
+            on_docking: self.on_docking.wire2api(),
+            docking: self.docking.wire2api(),
+        }
+    }
+}
+impl Wire2Api<Vec<TuneNote>> for *mut wire_list_tune_note {
+    fn wire2api(self) -> Vec<TuneNote> {
+        let vec = unsafe {
+            let wrap = support::box_from_leak_ptr(self);
+            support::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        };
+        vec.into_iter().map(Wire2Api::wire2api).collect()
+    }
+}
+impl Wire2Api<ReconnectPolicy> for wire_ReconnectPolicy {
+    fn wire2api(self) -> ReconnectPolicy {
+        ReconnectPolicy {
+            enabled: self.enabled.wire2api(),
+            retry_interval_ms: self.retry_interval_ms.wire2api(),
+            max_retry_interval_ms: self.max_retry_interval_ms.wire2api(),
+            backoff_multiplier: self.backoff_multiplier.wire2api(),
+            max_attempts: self.max_attempts.wire2api(),
+            relocate_by_serial_number: self.relocate_by_serial_number.wire2api(),
+        }
+    }
+}
+impl Wire2Api<String> for *mut wire_uint_8_list {
+    fn wire2api(self) -> String {
+        let vec: Vec<u8> = self.wire2api();
+        String::from_utf8_lossy(&vec).into_owned()
+    }
+}
+impl Wire2Api<TuneNote> for wire_TuneNote {
+    fn wire2api(self) -> TuneNote {
+        TuneNote {
+            note: self.note.wire2api(),
+            beats: self.beats.wire2api(),
+        }
+    }
+}
+impl Wire2Api<Vec<u8>> for *mut wire_uint_8_list {
+    fn wire2api(self) -> Vec<u8> {
+        unsafe {
+            let wrap = support::box_from_leak_ptr(self);
+            support::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        }
+    }
+}
+// Section: wire structs
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_ConnectionConfig {
+    baud_rate: u32,
+    read_timeout_ms: u32,
+    read_strategy: i32,
+    poll_interval_ms: u32,
+    buffer_size: u32,
+    flow_control: i32,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_EventSoundPolicy {
+    enabled: bool,
+    on_connected: bool,
+    connected: i32,
+    on_bump: bool,
+    bump: i32,
+    on_low_battery: bool,
+    low_battery: i32,
+    low_battery_percentage: f64,
+    on_docking: bool,
+    docking: i32,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_list_tune_note {
+    ptr: *mut wire_TuneNote,
+    len: i32,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_ReconnectPolicy {
+    enabled: bool,
+    retry_interval_ms: u32,
+    max_retry_interval_ms: u32,
+    backoff_multiplier: f64,
+    max_attempts: u32,
+    relocate_by_serial_number: bool,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_TuneNote {
+    note: *mut wire_uint_8_list,
+    beats: f64,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_uint_8_list {
+    ptr: *mut u8,
+    len: i32,
+}
+
+// Section: impl NewWithNullPtr
+
+pub trait NewWithNullPtr {
+    fn new_with_null_ptr() -> Self;
+}
+
+impl<T> NewWithNullPtr for *mut T {
+    fn new_with_null_ptr() -> Self {
+        std::ptr::null_mut()
+    }
+}
+
+impl NewWithNullPtr for wire_ConnectionConfig {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            baud_rate: Default::default(),
+            read_timeout_ms: Default::default(),
+            read_strategy: Default::default(),
+            poll_interval_ms: Default::default(),
+            buffer_size: Default::default(),
+            flow_control: Default::default(),
+        }
+    }
+}
+
+impl Default for wire_ConnectionConfig {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_EventSoundPolicy {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            enabled: Default::default(),
+            on_connected: Default::default(),
+            connected: Default::default(),
+            on_bump: Default::default(),
+            bump: Default::default(),
+            on_low_battery: Default::default(),
+            low_battery: Default::default(),
+            low_battery_percentage: Default::default(),
+            on_docking: Default::default(),
+            docking: Default::default(),
+        }
+    }
+}
+
+impl Default for wire_EventSoundPolicy {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_ReconnectPolicy {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            enabled: Default::default(),
+            retry_interval_ms: Default::default(),
+            max_retry_interval_ms: Default::default(),
+            backoff_multiplier: Default::default(),
+            max_attempts: Default::default(),
+            relocate_by_serial_number: Default::default(),
+        }
+    }
+}
+
+impl Default for wire_ReconnectPolicy {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_TuneNote {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            note: core::ptr::null_mut(),
+            beats: Default::default(),
+        }
+    }
+}
+
+impl Default for wire_TuneNote {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+// Section: sync execution mode utility
+
+#[no_mangle]
+pub extern "C" fn free_WireSyncReturn(ptr: support::WireSyncReturn) {
+    unsafe {
+        let _ = support::box_from_leak_ptr(ptr);
+    };
+}
@@ -3,43 +3,130 @@
     unused,
     clippy::redundant_closure,
     clippy::useless_conversion,
-    non_snake_case
+    clippy::unit_arg,
+    clippy::double_parens,
+    non_snake_case,
+    clippy::too_many_arguments
 )]
 // AUTO GENERATED FILE, DO NOT EDIT.
-// Generated by `flutter_rust_bridge`.
+// Generated by `flutter_rust_bridge`@ 1.82.6.
 
 use crate::api::*;
+use core::panic::UnwindSafe;
+use flutter_rust_bridge::rust2dart::IntoIntoDart;
 use flutter_rust_bridge::*;
+use std::ffi::c_void;
+use std::sync::Arc;
+
+// Section: imports
 
 // Section: wire functions
 
-#[no_mangle]
-pub extern "C" fn wire_spawn_turtlebot(port_: i64) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+fn wire_new_turtlebot_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, u32, _>(
+        WrapInfo {
+            debug_name: "new_turtlebot",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| new_turtlebot(),
+    )
+}
+fn wire_spawn_turtlebot_impl(port_: MessagePort, handle: impl Wire2Api<u32> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
         WrapInfo {
             debug_name: "spawn_turtlebot",
             port: Some(port_),
             mode: FfiCallMode::Stream,
         },
-        move || move |task_callback| spawn_turtlebot(task_callback.stream_sink()),
+        move || {
+            let api_handle = handle.wire2api();
+            move |task_callback| {
+                spawn_turtlebot(api_handle, task_callback.stream_sink::<_, String>())
+            }
+        },
     )
 }
-
-#[no_mangle]
-pub extern "C" fn wire_receive_from_turtlebot(port_: i64) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+fn wire_remove_turtlebot_impl(port_: MessagePort, handle: impl Wire2Api<u32> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "remove_turtlebot",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_handle = handle.wire2api();
+            move |task_callback| remove_turtlebot(api_handle)
+        },
+    )
+}
+fn wire_receive_from_turtlebot_impl(port_: MessagePort, handle: impl Wire2Api<u32> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<Feedback>, _>(
         WrapInfo {
             debug_name: "receive_from_turtlebot",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || move |task_callback| receive_from_turtlebot(),
+        move || {
+            let api_handle = handle.wire2api();
+            move |task_callback| receive_from_turtlebot(api_handle)
+        },
     )
 }
-
-#[no_mangle]
-pub extern "C" fn wire_search_port_command(port_: i64) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+fn wire_receive_device_events_impl(port_: MessagePort, handle: impl Wire2Api<u32> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<DeviceEvent>, _>(
+        WrapInfo {
+            debug_name: "receive_device_events",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_handle = handle.wire2api();
+            move |task_callback| receive_device_events(api_handle)
+        },
+    )
+}
+fn wire_latency_command_impl(port_: MessagePort, handle: impl Wire2Api<u32> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, LatencyStats, _>(
+        WrapInfo {
+            debug_name: "latency_command",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_handle = handle.wire2api();
+            move |task_callback| latency_command(api_handle)
+        },
+    )
+}
+fn wire_decoder_stats_command_impl(port_: MessagePort, handle: impl Wire2Api<u32> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, DecoderStats, _>(
+        WrapInfo {
+            debug_name: "decoder_stats_command",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_handle = handle.wire2api();
+            move |task_callback| decoder_stats_command(api_handle)
+        },
+    )
+}
+fn wire_battery_status_command_impl(port_: MessagePort, handle: impl Wire2Api<u32> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, BatteryStatus, _>(
+        WrapInfo {
+            debug_name: "battery_status_command",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_handle = handle.wire2api();
+            move |task_callback| battery_status_command(api_handle)
+        },
+    )
+}
+fn wire_search_port_command_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<String>, _>(
         WrapInfo {
             debug_name: "search_port_command",
             port: Some(port_),
@@ -48,122 +135,431 @@ pub extern "C" fn wire_search_port_command(port_: i64) {
         move || move |task_callback| search_port_command(),
     )
 }
-
-#[no_mangle]
-pub extern "C" fn wire_open_port_command(port_: i64, serial_port: *mut wire_uint_8_list) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+fn wire_search_port_descriptors_command_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<PortDescriptor>, _>(
+        WrapInfo {
+            debug_name: "search_port_descriptors_command",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| search_port_descriptors_command(),
+    )
+}
+fn wire_open_port_command_impl(
+    port_: MessagePort,
+    handle: impl Wire2Api<u32> + UnwindSafe,
+    serial_port: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
         WrapInfo {
             debug_name: "open_port_command",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
+            let api_handle = handle.wire2api();
             let api_serial_port = serial_port.wire2api();
-            move |task_callback| open_port_command(api_serial_port)
+            move |task_callback| open_port_command(api_handle, api_serial_port)
         },
     )
 }
-
-#[no_mangle]
-pub extern "C" fn wire_close_port_command(port_: i64) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+fn wire_open_port_with_config_command_impl(
+    port_: MessagePort,
+    handle: impl Wire2Api<u32> + UnwindSafe,
+    serial_port: impl Wire2Api<String> + UnwindSafe,
+    config: impl Wire2Api<ConnectionConfig> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "open_port_with_config_command",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_handle = handle.wire2api();
+            let api_serial_port = serial_port.wire2api();
+            let api_config = config.wire2api();
+            move |task_callback| {
+                open_port_with_config_command(api_handle, api_serial_port, api_config)
+            }
+        },
+    )
+}
+fn wire_close_port_command_impl(port_: MessagePort, handle: impl Wire2Api<u32> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
         WrapInfo {
             debug_name: "close_port_command",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || move |task_callback| close_port_command(),
+        move || {
+            let api_handle = handle.wire2api();
+            move |task_callback| close_port_command(api_handle)
+        },
     )
 }
-
-#[no_mangle]
-pub extern "C" fn wire_base_control_command(port_: i64, speed: u16, radius: u16) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+fn wire_set_event_sound_policy_command_impl(
+    port_: MessagePort,
+    handle: impl Wire2Api<u32> + UnwindSafe,
+    policy: impl Wire2Api<EventSoundPolicy> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "set_event_sound_policy_command",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_handle = handle.wire2api();
+            let api_policy = policy.wire2api();
+            move |task_callback| set_event_sound_policy_command(api_handle, api_policy)
+        },
+    )
+}
+fn wire_set_reconnect_policy_command_impl(
+    port_: MessagePort,
+    handle: impl Wire2Api<u32> + UnwindSafe,
+    policy: impl Wire2Api<ReconnectPolicy> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "set_reconnect_policy_command",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_handle = handle.wire2api();
+            let api_policy = policy.wire2api();
+            move |task_callback| set_reconnect_policy_command(api_handle, api_policy)
+        },
+    )
+}
+fn wire_start_capture_command_impl(
+    port_: MessagePort,
+    handle: impl Wire2Api<u32> + UnwindSafe,
+    path: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+        WrapInfo {
+            debug_name: "start_capture_command",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_handle = handle.wire2api();
+            let api_path = path.wire2api();
+            move |task_callback| start_capture_command(api_handle, api_path)
+        },
+    )
+}
+fn wire_stop_capture_command_impl(port_: MessagePort, handle: impl Wire2Api<u32> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "stop_capture_command",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_handle = handle.wire2api();
+            move |task_callback| stop_capture_command(api_handle)
+        },
+    )
+}
+fn wire_replay_mode_command_impl(
+    port_: MessagePort,
+    handle: impl Wire2Api<u32> + UnwindSafe,
+    mode: impl Wire2Api<ReplayMode> + UnwindSafe,
+    speed: impl Wire2Api<f64> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "replay_mode_command",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_handle = handle.wire2api();
+            let api_mode = mode.wire2api();
+            let api_speed = speed.wire2api();
+            move |task_callback| replay_mode_command(api_handle, api_mode, api_speed)
+        },
+    )
+}
+fn wire_replay_pause_command_impl(
+    port_: MessagePort,
+    handle: impl Wire2Api<u32> + UnwindSafe,
+    paused: impl Wire2Api<bool> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "replay_pause_command",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_handle = handle.wire2api();
+            let api_paused = paused.wire2api();
+            move |task_callback| replay_pause_command(api_handle, api_paused)
+        },
+    )
+}
+fn wire_replay_seek_command_impl(
+    port_: MessagePort,
+    handle: impl Wire2Api<u32> + UnwindSafe,
+    position_ms: impl Wire2Api<u64> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "replay_seek_command",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_handle = handle.wire2api();
+            let api_position_ms = position_ms.wire2api();
+            move |task_callback| replay_seek_command(api_handle, api_position_ms)
+        },
+    )
+}
+fn wire_replay_step_command_impl(port_: MessagePort, handle: impl Wire2Api<u32> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "replay_step_command",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_handle = handle.wire2api();
+            move |task_callback| replay_step_command(api_handle)
+        },
+    )
+}
+fn wire_drive_command_impl(
+    port_: MessagePort,
+    handle: impl Wire2Api<u32> + UnwindSafe,
+    linear: impl Wire2Api<f64> + UnwindSafe,
+    angular: impl Wire2Api<f64> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "drive_command",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_handle = handle.wire2api();
+            let api_linear = linear.wire2api();
+            let api_angular = angular.wire2api();
+            move |task_callback| drive_command(api_handle, api_linear, api_angular)
+        },
+    )
+}
+fn wire_base_control_command_impl(
+    port_: MessagePort,
+    handle: impl Wire2Api<u32> + UnwindSafe,
+    speed: impl Wire2Api<i16> + UnwindSafe,
+    radius: impl Wire2Api<i16> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
         WrapInfo {
             debug_name: "base_control_command",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
+            let api_handle = handle.wire2api();
             let api_speed = speed.wire2api();
             let api_radius = radius.wire2api();
-            move |task_callback| base_control_command(api_speed, api_radius)
+            move |task_callback| base_control_command(api_handle, api_speed, api_radius)
         },
     )
 }
-
-#[no_mangle]
-pub extern "C" fn wire_sound_command(port_: i64, freq: u8, amp: u8, duration: u8) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+fn wire_sound_command_impl(
+    port_: MessagePort,
+    handle: impl Wire2Api<u32> + UnwindSafe,
+    frequency: impl Wire2Api<f64> + UnwindSafe,
+    duration_ms: impl Wire2Api<u8> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
         WrapInfo {
             debug_name: "sound_command",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_freq = freq.wire2api();
-            let api_amp = amp.wire2api();
-            let api_duration = duration.wire2api();
-            move |task_callback| sound_command(api_freq, api_amp, api_duration)
+            let api_handle = handle.wire2api();
+            let api_frequency = frequency.wire2api();
+            let api_duration_ms = duration_ms.wire2api();
+            move |task_callback| sound_command(api_handle, api_frequency, api_duration_ms)
         },
     )
 }
-
-#[no_mangle]
-pub extern "C" fn wire_sound_sequence_command(port_: i64, seq: u8) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+fn wire_note_command_impl(
+    port_: MessagePort,
+    handle: impl Wire2Api<u32> + UnwindSafe,
+    note: impl Wire2Api<String> + UnwindSafe,
+    duration_ms: impl Wire2Api<u8> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "note_command",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_handle = handle.wire2api();
+            let api_note = note.wire2api();
+            let api_duration_ms = duration_ms.wire2api();
+            move |task_callback| note_command(api_handle, api_note, api_duration_ms)
+        },
+    )
+}
+fn wire_play_tune_command_impl(
+    port_: MessagePort,
+    handle: impl Wire2Api<u32> + UnwindSafe,
+    notes: impl Wire2Api<Vec<TuneNote>> + UnwindSafe,
+    tempo_bpm: impl Wire2Api<f64> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "play_tune_command",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_handle = handle.wire2api();
+            let api_notes = notes.wire2api();
+            let api_tempo_bpm = tempo_bpm.wire2api();
+            move |task_callback| play_tune_command(api_handle, api_notes, api_tempo_bpm)
+        },
+    )
+}
+fn wire_play_rtttl_command_impl(
+    port_: MessagePort,
+    handle: impl Wire2Api<u32> + UnwindSafe,
+    rtttl: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "play_rtttl_command",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_handle = handle.wire2api();
+            let api_rtttl = rtttl.wire2api();
+            move |task_callback| play_rtttl_command(api_handle, api_rtttl)
+        },
+    )
+}
+fn wire_tune_progress_command_impl(port_: MessagePort, handle: impl Wire2Api<u32> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, TuneProgress, _>(
+        WrapInfo {
+            debug_name: "tune_progress_command",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_handle = handle.wire2api();
+            move |task_callback| tune_progress_command(api_handle)
+        },
+    )
+}
+fn wire_set_tune_tempo_command_impl(
+    port_: MessagePort,
+    handle: impl Wire2Api<u32> + UnwindSafe,
+    tempo_bpm: impl Wire2Api<f64> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "set_tune_tempo_command",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_handle = handle.wire2api();
+            let api_tempo_bpm = tempo_bpm.wire2api();
+            move |task_callback| set_tune_tempo_command(api_handle, api_tempo_bpm)
+        },
+    )
+}
+fn wire_stop_tune_command_impl(port_: MessagePort, handle: impl Wire2Api<u32> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "stop_tune_command",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_handle = handle.wire2api();
+            move |task_callback| stop_tune_command(api_handle)
+        },
+    )
+}
+fn wire_sound_sequence_command_impl(
+    port_: MessagePort,
+    handle: impl Wire2Api<u32> + UnwindSafe,
+    seq: impl Wire2Api<SoundSequence> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
         WrapInfo {
             debug_name: "sound_sequence_command",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
+            let api_handle = handle.wire2api();
             let api_seq = seq.wire2api();
-            move |task_callback| sound_sequence_command(api_seq)
+            move |task_callback| sound_sequence_command(api_handle, api_seq)
         },
     )
 }
-
-#[no_mangle]
-pub extern "C" fn wire_request_extra_command(port_: i64, hw_ver: bool, fw_ver: bool, udid: bool) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+fn wire_request_extra_command_impl(
+    port_: MessagePort,
+    handle: impl Wire2Api<u32> + UnwindSafe,
+    hw_ver: impl Wire2Api<bool> + UnwindSafe,
+    fw_ver: impl Wire2Api<bool> + UnwindSafe,
+    udid: impl Wire2Api<bool> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
         WrapInfo {
             debug_name: "request_extra_command",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
+            let api_handle = handle.wire2api();
             let api_hw_ver = hw_ver.wire2api();
             let api_fw_ver = fw_ver.wire2api();
             let api_udid = udid.wire2api();
-            move |task_callback| request_extra_command(api_hw_ver, api_fw_ver, api_udid)
-        },
-    )
-}
-
-#[no_mangle]
-pub extern "C" fn wire_general_purpose_output_command(
-    port_: i64,
-    d_out_ch0: bool,
-    d_out_ch1: bool,
-    d_out_ch2: bool,
-    d_out_ch3: bool,
-    power_3v3: bool,
-    power_5v0: bool,
-    power_12v5a: bool,
-    power_12v1a5: bool,
-    red_led1: bool,
-    red_led2: bool,
-    green_led1: bool,
-    green_led2: bool,
+            move |task_callback| request_extra_command(api_handle, api_hw_ver, api_fw_ver, api_udid)
+        },
+    )
+}
+fn wire_general_purpose_output_command_impl(
+    port_: MessagePort,
+    handle: impl Wire2Api<u32> + UnwindSafe,
+    d_out_ch0: impl Wire2Api<bool> + UnwindSafe,
+    d_out_ch1: impl Wire2Api<bool> + UnwindSafe,
+    d_out_ch2: impl Wire2Api<bool> + UnwindSafe,
+    d_out_ch3: impl Wire2Api<bool> + UnwindSafe,
+    power_3v3: impl Wire2Api<bool> + UnwindSafe,
+    power_5v0: impl Wire2Api<bool> + UnwindSafe,
+    power_12v5a: impl Wire2Api<bool> + UnwindSafe,
+    power_12v1a5: impl Wire2Api<bool> + UnwindSafe,
+    red_led1: impl Wire2Api<bool> + UnwindSafe,
+    red_led2: impl Wire2Api<bool> + UnwindSafe,
+    green_led1: impl Wire2Api<bool> + UnwindSafe,
+    green_led2: impl Wire2Api<bool> + UnwindSafe,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
         WrapInfo {
             debug_name: "general_purpose_output_command",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
+            let api_handle = handle.wire2api();
             let api_d_out_ch0 = d_out_ch0.wire2api();
             let api_d_out_ch1 = d_out_ch1.wire2api();
             let api_d_out_ch2 = d_out_ch2.wire2api();
@@ -178,6 +574,7 @@ pub extern "C" fn wire_general_purpose_output_command(
             let api_green_led2 = green_led2.wire2api();
             move |task_callback| {
                 general_purpose_output_command(
+                    api_handle,
                     api_d_out_ch0,
                     api_d_out_ch1,
                     api_d_out_ch2,
@@ -195,66 +592,52 @@ pub extern "C" fn wire_general_purpose_output_command(
         },
     )
 }
-
-#[no_mangle]
-pub extern "C" fn wire_set_controller_gain_command(
-    port_: i64,
-    is_user_configured: bool,
-    p: u32,
-    i: f32,
-    d: u32,
+fn wire_set_controller_gain_command_impl(
+    port_: MessagePort,
+    handle: impl Wire2Api<u32> + UnwindSafe,
+    is_user_configured: impl Wire2Api<bool> + UnwindSafe,
+    p: impl Wire2Api<u32> + UnwindSafe,
+    i: impl Wire2Api<f32> + UnwindSafe,
+    d: impl Wire2Api<u32> + UnwindSafe,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
         WrapInfo {
             debug_name: "set_controller_gain_command",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
+            let api_handle = handle.wire2api();
             let api_is_user_configured = is_user_configured.wire2api();
             let api_p = p.wire2api();
             let api_i = i.wire2api();
             let api_d = d.wire2api();
             move |task_callback| {
-                set_controller_gain_command(api_is_user_configured, api_p, api_i, api_d)
+                set_controller_gain_command(api_handle, api_is_user_configured, api_p, api_i, api_d)
             }
         },
     )
 }
-
-#[no_mangle]
-pub extern "C" fn wire_get_controller_gain(port_: i64) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+fn wire_get_controller_gain_impl(port_: MessagePort, handle: impl Wire2Api<u32> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
         WrapInfo {
             debug_name: "get_controller_gain",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || move |task_callback| get_controller_gain(),
+        move || {
+            let api_handle = handle.wire2api();
+            move |task_callback| get_controller_gain(api_handle)
+        },
     )
 }
+// Section: wrapper structs
 
-// Section: wire structs
-
-#[repr(C)]
-#[derive(Clone)]
-pub struct wire_uint_8_list {
-    ptr: *mut u8,
-    len: i32,
-}
-
-// Section: wire enums
+// Section: static checks
 
 // Section: allocate functions
 
-#[no_mangle]
-pub extern "C" fn new_uint_8_list(len: i32) -> *mut wire_uint_8_list {
-    let ans = wire_uint_8_list {
-        ptr: support::new_leak_vec_ptr(Default::default(), len),
-        len,
-    };
-    support::new_leak_box_ptr(ans)
-}
+// Section: related functions
 
 // Section: impl Wire2Api
 
@@ -267,18 +650,7 @@ where
     *mut S: Wire2Api<T>,
 {
     fn wire2api(self) -> Option<T> {
-        if self.is_null() {
-            None
-        } else {
-            Some(self.wire2api())
-        }
-    }
-}
-
-impl Wire2Api<String> for *mut wire_uint_8_list {
-    fn wire2api(self) -> String {
-        let vec: Vec<u8> = self.wire2api();
-        String::from_utf8_lossy(&vec).into_owned()
+        (!self.is_null()).then(|| self.wire2api())
     }
 }
 
@@ -287,246 +659,653 @@ impl Wire2Api<bool> for bool {
         self
     }
 }
-
 impl Wire2Api<f32> for f32 {
     fn wire2api(self) -> f32 {
         self
     }
 }
-
-impl Wire2Api<u16> for u16 {
-    fn wire2api(self) -> u16 {
+impl Wire2Api<f64> for f64 {
+    fn wire2api(self) -> f64 {
         self
     }
 }
-
+impl Wire2Api<FlowControl> for i32 {
+    fn wire2api(self) -> FlowControl {
+        match self {
+            0 => FlowControl::None,
+            1 => FlowControl::Software,
+            2 => FlowControl::Hardware,
+            _ => unreachable!("Invalid variant for FlowControl: {}", self),
+        }
+    }
+}
+impl Wire2Api<i16> for i16 {
+    fn wire2api(self) -> i16 {
+        self
+    }
+}
+impl Wire2Api<ReadStrategy> for i32 {
+    fn wire2api(self) -> ReadStrategy {
+        match self {
+            0 => ReadStrategy::Poll,
+            1 => ReadStrategy::Blocking,
+            _ => unreachable!("Invalid variant for ReadStrategy: {}", self),
+        }
+    }
+}
+impl Wire2Api<ReplayMode> for i32 {
+    fn wire2api(self) -> ReplayMode {
+        match self {
+            0 => ReplayMode::RealTime,
+            1 => ReplayMode::Accelerated,
+            2 => ReplayMode::SingleStep,
+            _ => unreachable!("Invalid variant for ReplayMode: {}", self),
+        }
+    }
+}
+impl Wire2Api<SoundSequence> for i32 {
+    fn wire2api(self) -> SoundSequence {
+        match self {
+            0 => SoundSequence::On,
+            1 => SoundSequence::Off,
+            2 => SoundSequence::Recharge,
+            3 => SoundSequence::Button,
+            4 => SoundSequence::Error,
+            5 => SoundSequence::CleaningStart,
+            6 => SoundSequence::CleaningEnd,
+            _ => unreachable!("Invalid variant for SoundSequence: {}", self),
+        }
+    }
+}
 impl Wire2Api<u32> for u32 {
     fn wire2api(self) -> u32 {
         self
     }
 }
-
+impl Wire2Api<u64> for u64 {
+    fn wire2api(self) -> u64 {
+        self
+    }
+}
 impl Wire2Api<u8> for u8 {
     fn wire2api(self) -> u8 {
         self
     }
 }
+// Section: impl IntoDart
 
-impl Wire2Api<Vec<u8>> for *mut wire_uint_8_list {
-    fn wire2api(self) -> Vec<u8> {
-        unsafe {
-            let wrap = support::box_from_leak_ptr(self);
-            support::vec_from_leak_ptr(wrap.ptr, wrap.len)
-        }
+impl support::IntoDart for BasicSensor {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.valid.into_into_dart().into_dart(),
+            self.time_stamp.into_into_dart().into_dart(),
+            self.bumper.into_into_dart().into_dart(),
+            self.wheel_drop.into_into_dart().into_dart(),
+            self.cliff.into_into_dart().into_dart(),
+            self.left_encoder.into_into_dart().into_dart(),
+            self.right_encoder.into_into_dart().into_dart(),
+            self.left_pwm.into_into_dart().into_dart(),
+            self.right_pwm.into_into_dart().into_dart(),
+            self.button.into_into_dart().into_dart(),
+            self.charger.into_into_dart().into_dart(),
+            self.battery.into_into_dart().into_dart(),
+            self.battery_volts.into_into_dart().into_dart(),
+            self.overcurrent_flags.into_into_dart().into_dart(),
+            self.bumpers.into_into_dart().into_dart(),
+            self.wheel_drops.into_into_dart().into_dart(),
+            self.cliffs.into_into_dart().into_dart(),
+            self.buttons.into_into_dart().into_dart(),
+            self.charger_state.into_into_dart().into_dart(),
+            self.overcurrents.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for BasicSensor {}
+impl rust2dart::IntoIntoDart<BasicSensor> for BasicSensor {
+    fn into_into_dart(self) -> Self {
+        self
     }
 }
 
-// Section: impl NewWithNullPtr
-
-pub trait NewWithNullPtr {
-    fn new_with_null_ptr() -> Self;
+impl support::IntoDart for BatteryState {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::Discharging => 0,
+            Self::Charging => 1,
+            Self::Charged => 2,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for BatteryState {}
+impl rust2dart::IntoIntoDart<BatteryState> for BatteryState {
+    fn into_into_dart(self) -> Self {
+        self
+    }
 }
 
-impl<T> NewWithNullPtr for *mut T {
-    fn new_with_null_ptr() -> Self {
-        std::ptr::null_mut()
+impl support::IntoDart for BatteryStatus {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.valid.into_into_dart().into_dart(),
+            self.volts.into_into_dart().into_dart(),
+            self.smoothed_volts.into_into_dart().into_dart(),
+            self.cells.into_into_dart().into_dart(),
+            self.percentage.into_into_dart().into_dart(),
+            self.state.into_into_dart().into_dart(),
+            self.time_remaining_sec.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for BatteryStatus {}
+impl rust2dart::IntoIntoDart<BatteryStatus> for BatteryStatus {
+    fn into_into_dart(self) -> Self {
+        self
     }
 }
 
-// Section: impl IntoDart
+impl support::IntoDart for BumperFlags {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.left.into_into_dart().into_dart(),
+            self.center.into_into_dart().into_dart(),
+            self.right.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for BumperFlags {}
+impl rust2dart::IntoIntoDart<BumperFlags> for BumperFlags {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
 
-impl support::IntoDart for BasicSensor {
-    fn into_dart(self) -> support::DartCObject {
+impl support::IntoDart for ButtonFlags {
+    fn into_dart(self) -> support::DartAbi {
         vec![
-            self.valid.into_dart(),
-            self.time_stamp.into_dart(),
-            self.bumper.into_dart(),
-            self.wheel_drop.into_dart(),
-            self.cliff.into_dart(),
-            self.left_encoder.into_dart(),
-            self.right_encoder.into_dart(),
-            self.left_pwm.into_dart(),
-            self.right_pwm.into_dart(),
-            self.button.into_dart(),
-            self.charger.into_dart(),
-            self.battery.into_dart(),
-            self.overcurrent_flags.into_dart(),
+            self.b0.into_into_dart().into_dart(),
+            self.b1.into_into_dart().into_dart(),
+            self.b2.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
-impl support::IntoDartExceptPrimitive for BasicSensor {}
+impl support::IntoDartExceptPrimitive for ButtonFlags {}
+impl rust2dart::IntoIntoDart<ButtonFlags> for ButtonFlags {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for ChargerState {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::Discharging => 0,
+            Self::DockingCharged => 1,
+            Self::DockingCharging => 2,
+            Self::AdapterCharged => 3,
+            Self::AdapterCharging => 4,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ChargerState {}
+impl rust2dart::IntoIntoDart<ChargerState> for ChargerState {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
 
 impl support::IntoDart for Cliff {
-    fn into_dart(self) -> support::DartCObject {
+    fn into_dart(self) -> support::DartAbi {
         vec![
-            self.valid.into_dart(),
-            self.right_cliff_sensor.into_dart(),
-            self.central_cliff_sensor.into_dart(),
-            self.left_cliff_sensor.into_dart(),
+            self.valid.into_into_dart().into_dart(),
+            self.right_cliff_sensor.into_into_dart().into_dart(),
+            self.central_cliff_sensor.into_into_dart().into_dart(),
+            self.left_cliff_sensor.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl support::IntoDartExceptPrimitive for Cliff {}
+impl rust2dart::IntoIntoDart<Cliff> for Cliff {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for CliffFlags {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.left.into_into_dart().into_dart(),
+            self.center.into_into_dart().into_dart(),
+            self.right.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for CliffFlags {}
+impl rust2dart::IntoIntoDart<CliffFlags> for CliffFlags {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
 
 impl support::IntoDart for ControllerInfo {
-    fn into_dart(self) -> support::DartCObject {
+    fn into_dart(self) -> support::DartAbi {
         vec![
-            self.valid.into_dart(),
-            self.is_user_configured.into_dart(),
-            self.p_gain.into_dart(),
-            self.i_gain.into_dart(),
-            self.d_gain.into_dart(),
+            self.valid.into_into_dart().into_dart(),
+            self.is_user_configured.into_into_dart().into_dart(),
+            self.p_gain.into_into_dart().into_dart(),
+            self.i_gain.into_into_dart().into_dart(),
+            self.d_gain.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl support::IntoDartExceptPrimitive for ControllerInfo {}
+impl rust2dart::IntoIntoDart<ControllerInfo> for ControllerInfo {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
 
 impl support::IntoDart for Current {
-    fn into_dart(self) -> support::DartCObject {
+    fn into_dart(self) -> support::DartAbi {
         vec![
-            self.valid.into_dart(),
-            self.left_motor.into_dart(),
-            self.right_motor.into_dart(),
+            self.valid.into_into_dart().into_dart(),
+            self.left_motor.into_into_dart().into_dart(),
+            self.right_motor.into_into_dart().into_dart(),
+            self.left_motor_amps.into_into_dart().into_dart(),
+            self.right_motor_amps.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl support::IntoDartExceptPrimitive for Current {}
+impl rust2dart::IntoIntoDart<Current> for Current {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for DecoderStats {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.frames_ok.into_into_dart().into_dart(),
+            self.crc_failures.into_into_dart().into_dart(),
+            self.resyncs.into_into_dart().into_dart(),
+            self.bytes_discarded.into_into_dart().into_dart(),
+            self.unknown_ids.into_into_dart().into_dart(),
+            self.truncated_sub_payloads.into_into_dart().into_dart(),
+            self.last_error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for DecoderStats {}
+impl rust2dart::IntoIntoDart<DecoderStats> for DecoderStats {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for DeviceEvent {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.attached.into_into_dart().into_dart(),
+            self.port.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for DeviceEvent {}
+impl rust2dart::IntoIntoDart<DeviceEvent> for DeviceEvent {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
 
 impl support::IntoDart for DockingIR {
-    fn into_dart(self) -> support::DartCObject {
+    fn into_dart(self) -> support::DartAbi {
         vec![
-            self.valid.into_dart(),
-            self.right_signal.into_dart(),
-            self.central_signal.into_dart(),
-            self.left_signal.into_dart(),
+            self.valid.into_into_dart().into_dart(),
+            self.right_signal.into_into_dart().into_dart(),
+            self.central_signal.into_into_dart().into_dart(),
+            self.left_signal.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl support::IntoDartExceptPrimitive for DockingIR {}
+impl rust2dart::IntoIntoDart<DockingIR> for DockingIR {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for EncoderTicks {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.valid.into_into_dart().into_dart(),
+            self.left.into_into_dart().into_dart(),
+            self.right.into_into_dart().into_dart(),
+            self.left_delta.into_into_dart().into_dart(),
+            self.right_delta.into_into_dart().into_dart(),
+            self.time_stamp_ms.into_into_dart().into_dart(),
+            self.dt_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for EncoderTicks {}
+impl rust2dart::IntoIntoDart<EncoderTicks> for EncoderTicks {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
 
 impl support::IntoDart for Feedback {
-    fn into_dart(self) -> support::DartCObject {
+    fn into_dart(self) -> support::DartAbi {
         vec![
-            self.epoch_time_stamp.into_dart(),
-            self.basic_sensor.into_dart(),
-            self.docking_ir.into_dart(),
-            self.inertial_sensor.into_dart(),
-            self.cliff.into_dart(),
-            self.current.into_dart(),
-            self.hardware_version.into_dart(),
-            self.firmware_version.into_dart(),
-            self.gyro.into_dart(),
-            self.general_purpose_input.into_dart(),
-            self.unique_device_id.into_dart(),
-            self.controller_info.into_dart(),
+            self.epoch_time_stamp.into_into_dart().into_dart(),
+            self.basic_sensor.into_into_dart().into_dart(),
+            self.docking_ir.into_into_dart().into_dart(),
+            self.inertial_sensor.into_into_dart().into_dart(),
+            self.cliff.into_into_dart().into_dart(),
+            self.current.into_into_dart().into_dart(),
+            self.hardware_version.into_into_dart().into_dart(),
+            self.firmware_version.into_into_dart().into_dart(),
+            self.gyro.into_into_dart().into_dart(),
+            self.general_purpose_input.into_into_dart().into_dart(),
+            self.unique_device_id.into_into_dart().into_dart(),
+            self.controller_info.into_into_dart().into_dart(),
+            self.battery.into_into_dart().into_dart(),
+            self.encoders.into_into_dart().into_dart(),
+            self.unknown_sub_payloads.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl support::IntoDartExceptPrimitive for Feedback {}
+impl rust2dart::IntoIntoDart<Feedback> for Feedback {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
 
 impl support::IntoDart for FirmwareVersion {
-    fn into_dart(self) -> support::DartCObject {
+    fn into_dart(self) -> support::DartAbi {
         vec![
-            self.valid.into_dart(),
-            self.patch.into_dart(),
-            self.minor.into_dart(),
-            self.major.into_dart(),
+            self.valid.into_into_dart().into_dart(),
+            self.patch.into_into_dart().into_dart(),
+            self.minor.into_into_dart().into_dart(),
+            self.major.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl support::IntoDartExceptPrimitive for FirmwareVersion {}
+impl rust2dart::IntoIntoDart<FirmwareVersion> for FirmwareVersion {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
 
 impl support::IntoDart for GeneralPurposeInput {
-    fn into_dart(self) -> support::DartCObject {
+    fn into_dart(self) -> support::DartAbi {
         vec![
-            self.valid.into_dart(),
-            self.d_ch0.into_dart(),
-            self.a_ch0.into_dart(),
-            self.a_ch1.into_dart(),
-            self.a_ch2.into_dart(),
-            self.a_ch3.into_dart(),
+            self.valid.into_into_dart().into_dart(),
+            self.d_ch0.into_into_dart().into_dart(),
+            self.a_ch0.into_into_dart().into_dart(),
+            self.a_ch1.into_into_dart().into_dart(),
+            self.a_ch2.into_into_dart().into_dart(),
+            self.a_ch3.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl support::IntoDartExceptPrimitive for GeneralPurposeInput {}
+impl rust2dart::IntoIntoDart<GeneralPurposeInput> for GeneralPurposeInput {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
 
 impl support::IntoDart for Gyro {
-    fn into_dart(self) -> support::DartCObject {
+    fn into_dart(self) -> support::DartAbi {
         vec![
-            self.valid.into_dart(),
-            self.frame_id.into_dart(),
-            self.followed_data_length.into_dart(),
-            self.x0.into_dart(),
-            self.y0.into_dart(),
-            self.z0.into_dart(),
-            self.x1.into_dart(),
-            self.y1.into_dart(),
-            self.z1.into_dart(),
-            self.x2.into_dart(),
-            self.y2.into_dart(),
-            self.z2.into_dart(),
+            self.valid.into_into_dart().into_dart(),
+            self.frame_id.into_into_dart().into_dart(),
+            self.followed_data_length.into_into_dart().into_dart(),
+            self.samples.into_into_dart().into_dart(),
+            self.x_rad_per_sec.into_into_dart().into_dart(),
+            self.y_rad_per_sec.into_into_dart().into_dart(),
+            self.z_rad_per_sec.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl support::IntoDartExceptPrimitive for Gyro {}
+impl rust2dart::IntoIntoDart<Gyro> for Gyro {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for GyroSample {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.frame_id.into_into_dart().into_dart(),
+            self.x.into_into_dart().into_dart(),
+            self.y.into_into_dart().into_dart(),
+            self.z.into_into_dart().into_dart(),
+            self.x_rad_per_sec.into_into_dart().into_dart(),
+            self.y_rad_per_sec.into_into_dart().into_dart(),
+            self.z_rad_per_sec.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for GyroSample {}
+impl rust2dart::IntoIntoDart<GyroSample> for GyroSample {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
 
 impl support::IntoDart for HardwareVersion {
-    fn into_dart(self) -> support::DartCObject {
+    fn into_dart(self) -> support::DartAbi {
         vec![
-            self.valid.into_dart(),
-            self.patch.into_dart(),
-            self.minor.into_dart(),
-            self.major.into_dart(),
+            self.valid.into_into_dart().into_dart(),
+            self.patch.into_into_dart().into_dart(),
+            self.minor.into_into_dart().into_dart(),
+            self.major.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl support::IntoDartExceptPrimitive for HardwareVersion {}
+impl rust2dart::IntoIntoDart<HardwareVersion> for HardwareVersion {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
 
 impl support::IntoDart for InertialSensor {
-    fn into_dart(self) -> support::DartCObject {
+    fn into_dart(self) -> support::DartAbi {
         vec![
-            self.valid.into_dart(),
-            self.angle.into_dart(),
-            self.angle_rate.into_dart(),
+            self.valid.into_into_dart().into_dart(),
+            self.angle.into_into_dart().into_dart(),
+            self.angle_rate.into_into_dart().into_dart(),
+            self.angle_deg.into_into_dart().into_dart(),
+            self.angle_rad.into_into_dart().into_dart(),
+            self.angle_rate_deg_per_sec.into_into_dart().into_dart(),
+            self.angle_rate_rad_per_sec.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl support::IntoDartExceptPrimitive for InertialSensor {}
+impl rust2dart::IntoIntoDart<InertialSensor> for InertialSensor {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for LatencyStats {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.samples.into_into_dart().into_dart(),
+            self.last_ms.into_into_dart().into_dart(),
+            self.mean_ms.into_into_dart().into_dart(),
+            self.max_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for LatencyStats {}
+impl rust2dart::IntoIntoDart<LatencyStats> for LatencyStats {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for MatchConfidence {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::Low => 0,
+            Self::Medium => 1,
+            Self::High => 2,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for MatchConfidence {}
+impl rust2dart::IntoIntoDart<MatchConfidence> for MatchConfidence {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for OvercurrentFlags {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.left.into_into_dart().into_dart(),
+            self.right.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for OvercurrentFlags {}
+impl rust2dart::IntoIntoDart<OvercurrentFlags> for OvercurrentFlags {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for PortDescriptor {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.port_name.into_into_dart().into_dart(),
+            self.vid.into_into_dart().into_dart(),
+            self.pid.into_into_dart().into_dart(),
+            self.manufacturer.into_into_dart().into_dart(),
+            self.product.into_into_dart().into_dart(),
+            self.serial_number.into_into_dart().into_dart(),
+            self.confidence.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for PortDescriptor {}
+impl rust2dart::IntoIntoDart<PortDescriptor> for PortDescriptor {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for TuneProgress {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.playing.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.step.into_into_dart().into_dart(),
+            self.steps.into_into_dart().into_dart(),
+            self.elapsed_ms.into_into_dart().into_dart(),
+            self.duration_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for TuneProgress {}
+impl rust2dart::IntoIntoDart<TuneProgress> for TuneProgress {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
 
 impl support::IntoDart for UniqueDeviceId {
-    fn into_dart(self) -> support::DartCObject {
+    fn into_dart(self) -> support::DartAbi {
         vec![
-            self.valid.into_dart(),
-            self.udid0.into_dart(),
-            self.udid1.into_dart(),
-            self.udid2.into_dart(),
+            self.valid.into_into_dart().into_dart(),
+            self.udid0.into_into_dart().into_dart(),
+            self.udid1.into_into_dart().into_dart(),
+            self.udid2.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl support::IntoDartExceptPrimitive for UniqueDeviceId {}
+impl rust2dart::IntoIntoDart<UniqueDeviceId> for UniqueDeviceId {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for UnknownSubPayload {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.id.into_into_dart().into_dart(),
+            self.data.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for UnknownSubPayload {}
+impl rust2dart::IntoIntoDart<UnknownSubPayload> for UnknownSubPayload {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for WheelDropFlags {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.left.into_into_dart().into_dart(),
+            self.right.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for WheelDropFlags {}
+impl rust2dart::IntoIntoDart<WheelDropFlags> for WheelDropFlags {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
 
 // Section: executor
+
 support::lazy_static! {
     pub static ref FLUTTER_RUST_BRIDGE_HANDLER: support::DefaultHandler = Default::default();
 }
 
-// Section: sync execution mode utility
-
-#[no_mangle]
-pub extern "C" fn free_WireSyncReturnStruct(val: support::WireSyncReturnStruct) {
-    unsafe {
-        let _ = support::vec_from_leak_ptr(val.ptr, val.len);
-    }
-}
+#[cfg(not(target_family = "wasm"))]
+#[path = "bridge_generated.io.rs"]
+mod io;
+#[cfg(not(target_family = "wasm"))]
+pub use self::io::*;
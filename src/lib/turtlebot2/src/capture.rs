@@ -0,0 +1,111 @@
+use std::{
+    fs::File,
+    io::{BufRead, BufReader, LineWriter, Write},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{anyhow, Result};
+
+// Capture records the raw bytes of a port to a text file, one line per read/write:
+//   <host time in us since epoch> <RX|TX> <bytes in hex>
+// e.g. 1700000000123456 RX aa55430104...
+// Lines starting with # are comments (e.g. the port name).
+pub struct Capture {
+    writer: LineWriter<File>,
+}
+
+impl Capture {
+    pub fn start(path: &str, port_name: &str) -> Result<Capture> {
+        let mut writer = LineWriter::new(File::create(path)?);
+        writeln!(writer, "# port {}", port_name)?;
+        writeln!(writer, "# started {}", now_us())?;
+        Ok(Capture { writer })
+    }
+
+    // Bytes read from the port
+    pub fn rx(&mut self, bytes: &[u8]) -> Result<()> {
+        self.record("RX", bytes)
+    }
+
+    // Bytes written to the port
+    pub fn tx(&mut self, bytes: &[u8]) -> Result<()> {
+        self.record("TX", bytes)
+    }
+
+    // Marks what happened to the connection in the middle of a capture
+    pub fn note(&mut self, note: &str) -> Result<()> {
+        writeln!(self.writer, "# {} {}", note, now_us())?;
+        Ok(())
+    }
+
+    pub fn stop(mut self) -> Result<()> {
+        writeln!(self.writer, "# stopped {}", now_us())?;
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    fn record(&mut self, direction: &str, bytes: &[u8]) -> Result<()> {
+        let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+        // LineWriter flushes every line, so the file survives a crash
+        writeln!(self.writer, "{} {} {}", now_us(), direction, hex)?;
+        Ok(())
+    }
+}
+
+// A line of a capture file
+pub struct CaptureRecord {
+    pub time_us: u64,
+    // RX (read from the port) or TX (written to the port)
+    pub rx: bool,
+    pub bytes: Vec<u8>,
+}
+
+// Reads back a file written by Capture (comments are skipped)
+pub fn read_capture(path: &str) -> Result<Vec<CaptureRecord>> {
+    let reader = BufReader::new(File::open(path)?);
+    let mut records = Vec::new();
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let bad_line = || anyhow!("Bad capture line {}: {}", i + 1, line);
+        let mut fields = line.split_whitespace();
+        let time_us = fields
+            .next()
+            .and_then(|t| t.parse::<u64>().ok())
+            .ok_or_else(bad_line)?;
+        let rx = match fields.next() {
+            Some("RX") => true,
+            Some("TX") => false,
+            _ => return Err(bad_line()),
+        };
+        let hex = fields.next().unwrap_or("");
+        if hex.len() % 2 != 0 {
+            return Err(bad_line());
+        }
+        let bytes = (0..hex.len())
+            .step_by(2)
+            .map(|j| u8::from_str_radix(&hex[j..j + 2], 16))
+            .collect::<Result<Vec<u8>, _>>()
+            .map_err(|_| bad_line())?;
+        records.push(CaptureRecord { time_us, rx, bytes });
+    }
+    Ok(records)
+}
+
+// A timestamped file in the temp directory
+pub fn default_capture_path() -> String {
+    std::env::temp_dir()
+        .join(format!("kobuki_capture_{}.log", now_us() / 1000))
+        .to_string_lossy()
+        .to_string()
+}
+
+fn now_us() -> u128 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_micros()
+}
@@ -0,0 +1,103 @@
+use crate::api::{BasicSensor, EncoderTicks};
+
+// EncoderTracker unwraps the 16 bit encoders and time stamp of the basic sensor data.
+// The encoders wrap every 65536 ticks (about 6 m) and the time stamp every 65536 ms,
+// so a delta is taken as the shortest way around (the robot can't move 32768 ticks in 20 ms).
+// Should be fed every basic sensor data of a connection in order.
+pub struct EncoderTracker {
+    last: Option<(u16, u16, u16)>,
+    ticks: EncoderTicks,
+}
+
+impl EncoderTracker {
+    pub fn new() -> EncoderTracker {
+        EncoderTracker {
+            last: None,
+            ticks: EncoderTicks::default(),
+        }
+    }
+
+    pub fn update(&mut self, sensor: &BasicSensor) -> EncoderTicks {
+        let left = sensor.left_encoder as u16;
+        let right = sensor.right_encoder as u16;
+        let stamp = sensor.time_stamp as u16;
+
+        let t = &mut self.ticks;
+        match self.last {
+            // Starts from the raw values
+            None => {
+                t.valid = true;
+                t.left = left as i64;
+                t.right = right as i64;
+                t.time_stamp_ms = stamp as u64;
+            }
+            Some((last_left, last_right, last_stamp)) => {
+                t.left_delta = left.wrapping_sub(last_left) as i16 as i64;
+                t.right_delta = right.wrapping_sub(last_right) as i16 as i64;
+                t.dt_ms = stamp.wrapping_sub(last_stamp) as u64;
+                t.left += t.left_delta;
+                t.right += t.right_delta;
+                t.time_stamp_ms += t.dt_ms;
+            }
+        }
+        self.last = Some((left, right, stamp));
+        t.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sensor(left: u16, right: u16, time_stamp: u16) -> BasicSensor {
+        BasicSensor {
+            valid: true,
+            left_encoder: left.into(),
+            right_encoder: right.into(),
+            time_stamp: time_stamp.into(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn starts_from_raw() {
+        let mut tracker = EncoderTracker::new();
+        let t = tracker.update(&sensor(100, 65000, 500));
+        assert!(t.valid);
+        assert_eq!((t.left, t.right, t.time_stamp_ms), (100, 65000, 500));
+        assert_eq!((t.left_delta, t.right_delta, t.dt_ms), (0, 0, 0));
+    }
+
+    #[test]
+    fn forward_wrap() {
+        let mut tracker = EncoderTracker::new();
+        tracker.update(&sensor(65530, 65535, 0));
+        let t = tracker.update(&sensor(4, 9, 20));
+        assert_eq!((t.left_delta, t.right_delta), (10, 10));
+        assert_eq!((t.left, t.right), (65540, 65545));
+    }
+
+    #[test]
+    fn backward_wrap() {
+        let mut tracker = EncoderTracker::new();
+        tracker.update(&sensor(5, 0, 0));
+        let t = tracker.update(&sensor(65531, 65526, 20));
+        assert_eq!((t.left_delta, t.right_delta), (-10, -10));
+        assert_eq!((t.left, t.right), (-5, -10));
+        // And back over the wrap
+        let t = tracker.update(&sensor(1, 65535, 40));
+        assert_eq!((t.left_delta, t.right_delta), (6, 9));
+        assert_eq!((t.left, t.right), (1, -1));
+    }
+
+    #[test]
+    fn time_stamp_wrap() {
+        let mut tracker = EncoderTracker::new();
+        tracker.update(&sensor(0, 0, 65530));
+        let t = tracker.update(&sensor(0, 0, 14));
+        assert_eq!(t.dt_ms, 20);
+        assert_eq!(t.time_stamp_ms, 65550);
+        let t = tracker.update(&sensor(0, 0, 34));
+        assert_eq!(t.time_stamp_ms, 65570);
+    }
+}
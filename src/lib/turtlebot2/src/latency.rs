@@ -0,0 +1,70 @@
+use std::{collections::VecDeque, time::Instant};
+
+use crate::api::{Feedback, LatencyStats};
+
+// About 10 seconds of feedbacks at 50 Hz
+const WINDOW: usize = 500;
+
+// LatencyMeter estimates how late feedbacks reach Flutter.
+// The host and Kobuki clocks are not synchronized, so the delay of a feedback is
+// (host time - Kobuki time stamp) compared with the fastest feedback in the recent window.
+// The constant part (e.g. USB latency) is not included,
+// but the delay added by polling, batching and buffering is.
+pub struct LatencyMeter {
+    started: Instant,
+    last_stamp: Option<u16>,
+    robot_ms: i64,
+    offsets: VecDeque<f64>,
+    stats: LatencyStats,
+}
+
+impl LatencyMeter {
+    pub fn new() -> LatencyMeter {
+        LatencyMeter {
+            started: Instant::now(),
+            last_stamp: None,
+            robot_ms: 0,
+            offsets: VecDeque::with_capacity(WINDOW),
+            stats: LatencyStats::default(),
+        }
+    }
+
+    // Should be called when the Kobuki clock starts over (e.g. reconnected)
+    pub fn reset(&mut self) {
+        *self = LatencyMeter::new();
+    }
+
+    // To be called when Flutter takes the feedback
+    pub fn record(&mut self, feedback: &Feedback, now: Instant) {
+        // Only the basic sensor has the time stamp
+        if !feedback.basic_sensor.valid {
+            return;
+        }
+
+        // The time stamp (ms) circulates from 0 to 65535
+        let stamp = feedback.basic_sensor.time_stamp as u16;
+        match self.last_stamp {
+            Some(l) => self.robot_ms += stamp.wrapping_sub(l) as i64,
+            None => self.robot_ms = stamp as i64,
+        }
+        self.last_stamp = Some(stamp);
+
+        let host_ms = now.duration_since(self.started).as_secs_f64() * 1000.0;
+        let offset = host_ms - self.robot_ms as f64;
+        if self.offsets.len() == WINDOW {
+            self.offsets.pop_front();
+        }
+        self.offsets.push_back(offset);
+
+        let fastest = self.offsets.iter().cloned().fold(f64::INFINITY, f64::min);
+        let latency = offset - fastest;
+        self.stats.samples += 1;
+        self.stats.last_ms = latency;
+        self.stats.max_ms = self.stats.max_ms.max(latency);
+        self.stats.mean_ms += (latency - self.stats.mean_ms) / self.stats.samples as f64;
+    }
+
+    pub fn stats(&self) -> LatencyStats {
+        self.stats.clone()
+    }
+}
@@ -3,7 +3,15 @@ extern crate num;
 extern crate num_derive;
 
 mod api;
+mod battery;
+mod capture;
+mod encoder;
+mod latency;
+mod rtttl;
 mod rx;
+mod sound_policy;
+mod transport;
+mod tune;
 mod turtlebot2;
 mod tx;
 
@@ -0,0 +1,223 @@
+use anyhow::{anyhow, Result};
+
+use crate::tune::*;
+use crate::tx::*;
+
+// When the defaults section doesn't have them
+const DEFAULT_DURATION: u32 = 4;
+const DEFAULT_OCTAVE: i32 = 6;
+const DEFAULT_BPM: f64 = 63.0;
+
+// RTTTL (Ring Tone Text Transfer Language): name:defaults:notes
+// e.g. "Beep:d=8,o=5,b=120:c,e,g,2c6,p,16c."
+// - defaults: d (duration), o (octave), b (beats per minute, a beat is a quarter note)
+// - note: [duration]letter[#][.][octave][.] where the letter is a-g, h (= b) or p (a rest)
+pub fn parse_rtttl(text: &str) -> Result<Tune> {
+    let sections: Vec<&str> = text.trim().splitn(3, ':').collect();
+    if sections.len() != 3 {
+        return Err(anyhow!("What RTTTL? Expected name:defaults:notes"));
+    }
+    let name = sections[0].trim().to_string();
+
+    let mut duration = DEFAULT_DURATION;
+    let mut octave = DEFAULT_OCTAVE;
+    let mut bpm = DEFAULT_BPM;
+    for d in sections[1]
+        .split(',')
+        .map(str::trim)
+        .filter(|d| !d.is_empty())
+    {
+        let bad_default = || anyhow!("What default? {}", d);
+        let (key, value) = d.split_once('=').ok_or_else(bad_default)?;
+        match key.trim().to_ascii_lowercase().as_str() {
+            "d" => duration = check_duration(value.trim().parse().map_err(|_| bad_default())?)?,
+            "o" => {
+                octave = value.trim().parse().map_err(|_| bad_default())?;
+                if !(MIN_OCTAVE..=MAX_OCTAVE).contains(&octave) {
+                    return Err(bad_default());
+                }
+            }
+            "b" => bpm = value.trim().parse().map_err(|_| bad_default())?,
+            _ => return Err(bad_default()),
+        }
+    }
+    check_tempo(bpm)?;
+
+    let mut steps = Vec::new();
+    for n in sections[2]
+        .split(',')
+        .map(str::trim)
+        .filter(|n| !n.is_empty())
+    {
+        steps.push(parse_note(n, duration, octave)?);
+    }
+    if steps.is_empty() {
+        return Err(anyhow!("No notes in {}", name));
+    }
+
+    Ok(Tune {
+        name,
+        steps,
+        tempo_bpm: bpm,
+    })
+}
+
+fn parse_note(note: &str, default_duration: u32, default_octave: i32) -> Result<TuneStep> {
+    let bad_note = || anyhow!("What note? {}", note);
+    let lower = note.to_ascii_lowercase();
+    let mut rest = lower.as_str();
+
+    let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    let duration = match digits {
+        0 => default_duration,
+        _ => check_duration(rest[..digits].parse().map_err(|_| bad_note())?)?,
+    };
+    rest = &rest[digits..];
+
+    let mut chars = rest.chars();
+    let letter = match chars.next() {
+        Some('h') => 'b',
+        Some(c @ ('a'..='g' | 'p')) => c,
+        _ => return Err(bad_note()),
+    };
+    rest = chars.as_str();
+    let sharp = rest.starts_with('#');
+    if sharp {
+        rest = &rest[1..];
+    }
+    // The dot can be before or after the octave
+    let mut dotted = rest.starts_with('.');
+    if dotted {
+        rest = &rest[1..];
+    }
+    let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    let octave = match digits {
+        0 => default_octave,
+        _ => rest[..digits].parse().map_err(|_| bad_note())?,
+    };
+    rest = &rest[digits..];
+    // Only one dot
+    if rest == "." && !dotted {
+        dotted = true;
+    } else if !rest.is_empty() {
+        return Err(bad_note());
+    }
+
+    let mut beats = 4.0 / duration as f64;
+    if dotted {
+        beats *= 1.5;
+    }
+    let frequency = match letter {
+        'p' => None,
+        _ => {
+            let name = format!("{}{}{}", letter, if sharp { "#" } else { "" }, octave);
+            Some(note_frequency(&name)?)
+        }
+    };
+    Ok(TuneStep { frequency, beats })
+}
+
+// A whole note (1) to a 32nd note
+fn check_duration(duration: u32) -> Result<u32> {
+    if ![1, 2, 4, 8, 16, 32].contains(&duration) {
+        return Err(anyhow!("What duration? {}", duration));
+    }
+    Ok(duration)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // (Hz or None for a rest, beats)
+    fn steps(text: &str) -> Vec<(Option<f64>, f64)> {
+        parse_rtttl(text)
+            .unwrap()
+            .steps
+            .iter()
+            .map(|s| (s.frequency.map(|f| (f * 1000.0).round() / 1000.0), s.beats))
+            .collect()
+    }
+
+    #[test]
+    fn example() {
+        let tune = parse_rtttl("Beep:d=8,o=5,b=120:c,e,g,2c6,p,16c.").unwrap();
+        assert_eq!(tune.name, "Beep");
+        assert_eq!(tune.tempo_bpm, 120.0);
+        assert_eq!(
+            steps("Beep:d=8,o=5,b=120:c,e,g,2c6,p,16c."),
+            vec![
+                (Some(523.251), 0.5),
+                (Some(659.255), 0.5),
+                (Some(783.991), 0.5),
+                (Some(1046.502), 2.0),
+                (None, 0.5),
+                (Some(523.251), 0.375),
+            ]
+        );
+    }
+
+    #[test]
+    fn defaults() {
+        // d=4, o=6, b=63
+        let tune = parse_rtttl("x::a").unwrap();
+        assert_eq!(tune.tempo_bpm, 63.0);
+        assert_eq!(steps("x::a"), vec![(Some(1760.0), 1.0)]);
+        // Only some of them, in any order and case
+        assert_eq!(steps("x: O=5 , d=2 :a"), vec![(Some(880.0), 2.0)]);
+    }
+
+    #[test]
+    fn notes() {
+        assert_eq!(
+            steps("x:o=5:8a.,8a5.,8a.5,h,c#,C#4,p,4p."),
+            vec![
+                // Dotted, before or after the octave
+                (Some(880.0), 0.75),
+                (Some(880.0), 0.75),
+                (Some(880.0), 0.75),
+                // h is b
+                (Some(987.767), 1.0),
+                (Some(554.365), 1.0),
+                (Some(277.183), 1.0),
+                (None, 1.0),
+                (None, 1.5),
+            ]
+        );
+    }
+
+    #[test]
+    fn bad_durations() {
+        for text in ["x::3c", "x::64c", "x:d=3:c", "x:d=0:c"] {
+            let e = parse_rtttl(text).unwrap_err();
+            assert!(e.to_string().starts_with("What duration?"), "{}", text);
+        }
+    }
+
+    #[test]
+    fn bad_defaults() {
+        for text in ["x:d:c", "x:q=1:c", "x:d=four:c", "x:o=five:c", "x:b=fast:c"] {
+            let e = parse_rtttl(text).unwrap_err();
+            assert!(e.to_string().starts_with("What default?"), "{}", text);
+        }
+        assert!(parse_rtttl("x:b=0:c").is_err());
+    }
+
+    #[test]
+    fn bad_rtttl() {
+        for text in [
+            "x", "x:c", "x::", "x::i", "x::c5x", "x::c..", "x::#c", "x::.c",
+        ] {
+            assert!(parse_rtttl(text).is_err(), "{}", text);
+        }
+    }
+
+    #[test]
+    fn octave_out_of_range() {
+        for text in ["x:o=2147483647:c", "x:o=10:c", "x::c2147483647", "x::c10"] {
+            assert!(parse_rtttl(text).is_err(), "{}", text);
+        }
+        let e = parse_rtttl("x::c2147483647").unwrap_err();
+        assert_eq!(e.to_string(), "What note? c2147483647");
+    }
+}
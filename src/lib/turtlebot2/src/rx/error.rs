@@ -0,0 +1,32 @@
+use thiserror::Error;
+
+use crate::api::DecoderStats;
+
+// What can go wrong while turning bytes into feedbacks
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum DecodeError {
+    #[error("checksum mismatch (expected {expected:#04x}, found {found:#04x})")]
+    CrcMismatch { expected: u8, found: u8 },
+    #[error("zero length packet")]
+    ZeroLength,
+    #[error("sub-payload {id} needs {declared} bytes but got {available}")]
+    TruncatedSubPayload {
+        id: u8,
+        declared: usize,
+        available: usize,
+    },
+    #[error("unknown sub-payload id {0}")]
+    UnknownId(u8),
+}
+
+impl DecoderStats {
+    pub fn record(&mut self, e: &DecodeError) {
+        match e {
+            DecodeError::CrcMismatch { .. } => self.crc_failures += 1,
+            DecodeError::ZeroLength => {}
+            DecodeError::TruncatedSubPayload { .. } => self.truncated_sub_payloads += 1,
+            DecodeError::UnknownId(_) => self.unknown_ids += 1,
+        }
+        self.last_error = e.to_string();
+    }
+}
@@ -0,0 +1,149 @@
+use crate::api::*;
+
+// The bits of the flags in the basic sensor data
+const BUMPER_RIGHT: u8 = 0x01;
+const BUMPER_CENTER: u8 = 0x02;
+const BUMPER_LEFT: u8 = 0x04;
+const WHEEL_DROP_RIGHT: u8 = 0x01;
+const WHEEL_DROP_LEFT: u8 = 0x02;
+const CLIFF_RIGHT: u8 = 0x01;
+const CLIFF_CENTER: u8 = 0x02;
+const CLIFF_LEFT: u8 = 0x04;
+const BUTTON_B0: u8 = 0x01;
+const BUTTON_B1: u8 = 0x02;
+const BUTTON_B2: u8 = 0x04;
+// Charger: 0 (discharging), 2/6 (docking charged/charging), 18/22 (adapter charged/charging)
+const CHARGER_CHARGED: u8 = 0x02;
+const CHARGER_CHARGING: u8 = 0x04;
+const CHARGER_ADAPTER: u8 = 0x10;
+const OVERCURRENT_LEFT: u8 = 0x01;
+const OVERCURRENT_RIGHT: u8 = 0x02;
+
+impl From<u8> for BumperFlags {
+    fn from(b: u8) -> Self {
+        BumperFlags {
+            left: b & BUMPER_LEFT != 0,
+            center: b & BUMPER_CENTER != 0,
+            right: b & BUMPER_RIGHT != 0,
+        }
+    }
+}
+
+impl From<u8> for WheelDropFlags {
+    fn from(b: u8) -> Self {
+        WheelDropFlags {
+            left: b & WHEEL_DROP_LEFT != 0,
+            right: b & WHEEL_DROP_RIGHT != 0,
+        }
+    }
+}
+
+impl From<u8> for CliffFlags {
+    fn from(b: u8) -> Self {
+        CliffFlags {
+            left: b & CLIFF_LEFT != 0,
+            center: b & CLIFF_CENTER != 0,
+            right: b & CLIFF_RIGHT != 0,
+        }
+    }
+}
+
+impl From<u8> for ButtonFlags {
+    fn from(b: u8) -> Self {
+        ButtonFlags {
+            b0: b & BUTTON_B0 != 0,
+            b1: b & BUTTON_B1 != 0,
+            b2: b & BUTTON_B2 != 0,
+        }
+    }
+}
+
+impl From<u8> for ChargerState {
+    fn from(b: u8) -> Self {
+        // Either charged or charging while connected to a charger
+        if b & (CHARGER_CHARGED | CHARGER_CHARGING) == 0 {
+            return ChargerState::Discharging;
+        }
+        let charging = b & CHARGER_CHARGING != 0;
+        match (b & CHARGER_ADAPTER != 0, charging) {
+            (true, true) => ChargerState::AdapterCharging,
+            (true, false) => ChargerState::AdapterCharged,
+            (false, true) => ChargerState::DockingCharging,
+            (false, false) => ChargerState::DockingCharged,
+        }
+    }
+}
+
+impl From<u8> for OvercurrentFlags {
+    fn from(b: u8) -> Self {
+        OvercurrentFlags {
+            left: b & OVERCURRENT_LEFT != 0,
+            right: b & OVERCURRENT_RIGHT != 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn charger() {
+        let cases = [
+            (0, ChargerState::Discharging),
+            (2, ChargerState::DockingCharged),
+            (6, ChargerState::DockingCharging),
+            (18, ChargerState::AdapterCharged),
+            (22, ChargerState::AdapterCharging),
+        ];
+        for (b, expected) in cases.iter() {
+            assert_eq!(ChargerState::from(*b), *expected, "{}", b);
+        }
+    }
+
+    #[test]
+    fn bits() {
+        let bumper = |b: u8| {
+            let f = BumperFlags::from(b);
+            (f.left, f.center, f.right)
+        };
+        assert_eq!(bumper(0x00), (false, false, false));
+        assert_eq!(bumper(0x01), (false, false, true));
+        assert_eq!(bumper(0x02), (false, true, false));
+        assert_eq!(bumper(0x04), (true, false, false));
+
+        let cliff = |b: u8| {
+            let f = CliffFlags::from(b);
+            (f.left, f.center, f.right)
+        };
+        assert_eq!(cliff(0x00), (false, false, false));
+        assert_eq!(cliff(0x01), (false, false, true));
+        assert_eq!(cliff(0x02), (false, true, false));
+        assert_eq!(cliff(0x04), (true, false, false));
+
+        let wheel_drop = |b: u8| {
+            let f = WheelDropFlags::from(b);
+            (f.left, f.right)
+        };
+        assert_eq!(wheel_drop(0x00), (false, false));
+        assert_eq!(wheel_drop(0x01), (false, true));
+        assert_eq!(wheel_drop(0x02), (true, false));
+
+        let button = |b: u8| {
+            let f = ButtonFlags::from(b);
+            (f.b0, f.b1, f.b2)
+        };
+        assert_eq!(button(0x00), (false, false, false));
+        assert_eq!(button(0x01), (true, false, false));
+        assert_eq!(button(0x02), (false, true, false));
+        assert_eq!(button(0x04), (false, false, true));
+
+        let overcurrent = |b: u8| {
+            let f = OvercurrentFlags::from(b);
+            (f.left, f.right)
+        };
+        assert_eq!(overcurrent(0x00), (false, false));
+        assert_eq!(overcurrent(0x01), (true, false));
+        assert_eq!(overcurrent(0x02), (false, true));
+    }
+}
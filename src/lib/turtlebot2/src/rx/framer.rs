@@ -0,0 +1,218 @@
+use std::collections::VecDeque;
+
+use crate::api::DecoderStats;
+use crate::rx::DecodeError;
+
+// Where the framer is in a packet:
+// 0xaa | 0x55 | length | payload (length bytes) | checksum
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum State {
+    Preamble0,
+    Preamble1,
+    Length,
+    Payload,
+    Checksum,
+}
+
+// Framer finds Kobuki packets in a byte stream however it is chopped by reads.
+// The length byte decides where a packet ends (0xaa 0x55 inside a payload doesn't matter),
+// and the unfinished packet is kept until the next push.
+// If the checksum doesn't match, the preambles were not real,
+// so it searches again from the byte after the first preamble.
+pub struct Framer {
+    state: State,
+    frame: Vec<u8>,
+    length: usize,
+    stats: DecoderStats,
+}
+
+impl Framer {
+    pub fn new() -> Framer {
+        Framer {
+            state: State::Preamble0,
+            frame: Vec::with_capacity(256),
+            length: 0,
+            stats: DecoderStats::default(),
+        }
+    }
+
+    // Starts over with the stats cleared (e.g. the port is reopened)
+    pub fn reset(&mut self) {
+        self.restart();
+        self.stats = DecoderStats::default();
+    }
+
+    pub fn stats(&self) -> &DecoderStats {
+        &self.stats
+    }
+
+    // Errors found after framing (e.g. by format_feedback)
+    pub fn record(&mut self, e: &DecodeError) {
+        self.stats.record(e);
+    }
+
+    // Drops the unfinished packet
+    fn restart(&mut self) {
+        self.state = State::Preamble0;
+        self.frame.clear();
+        self.length = 0;
+    }
+
+    // Returns the complete packets (preambles to checksum) in order
+    pub fn push(&mut self, bytes: &[u8]) -> Vec<Vec<u8>> {
+        let mut frames = Vec::new();
+        let mut input: VecDeque<u8> = bytes.iter().copied().collect();
+        while let Some(b) = input.pop_front() {
+            if let Some(rescan) = self.step(b, &mut frames) {
+                // To be read again before the rest
+                for b in rescan.into_iter().rev() {
+                    input.push_front(b);
+                }
+            }
+        }
+        frames
+    }
+
+    // Returns the bytes to search again if the packet turned out to be broken
+    fn step(&mut self, b: u8, frames: &mut Vec<Vec<u8>>) -> Option<Vec<u8>> {
+        match self.state {
+            State::Preamble0 => {
+                if b == 0xaa {
+                    self.frame.push(b);
+                    self.state = State::Preamble1;
+                } else {
+                    self.stats.bytes_discarded += 1;
+                }
+            }
+            State::Preamble1 => match b {
+                0x55 => {
+                    self.frame.push(b);
+                    self.state = State::Length;
+                }
+                // Can be the first preamble again
+                0xaa => self.stats.bytes_discarded += 1,
+                _ => {
+                    self.stats.bytes_discarded += 2;
+                    self.restart();
+                }
+            },
+            State::Length => {
+                // A packet has at least one sub-payload
+                if b == 0 {
+                    self.stats.bytes_discarded += 1;
+                    return self.resync(DecodeError::ZeroLength);
+                }
+                self.frame.push(b);
+                self.length = b as usize;
+                self.state = State::Payload;
+            }
+            State::Payload => {
+                self.frame.push(b);
+                if self.frame.len() == self.length + 3 {
+                    self.state = State::Checksum;
+                }
+            }
+            State::Checksum => {
+                // XOR of the length and payload bytes
+                let expected = self.frame[2..].iter().fold(0, |acc, c| acc ^ c);
+                self.frame.push(b);
+                if expected != b {
+                    return self.resync(DecodeError::CrcMismatch { expected, found: b });
+                }
+                self.stats.frames_ok += 1;
+                frames.push(std::mem::take(&mut self.frame));
+                self.restart();
+            }
+        }
+        None
+    }
+
+    // Only the first preamble is dropped, the rest will be searched again
+    fn resync(&mut self, e: DecodeError) -> Option<Vec<u8>> {
+        self.stats.record(&e);
+        self.stats.resyncs += 1;
+        self.stats.bytes_discarded += 1;
+        let rescan = self.frame.split_off(1);
+        self.restart();
+        Some(rescan)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rx::packet;
+
+    const GOOD: [u8; 6] = [0x04, 0x04, 0x01, 0x02, 0x03, 0x04];
+
+    #[test]
+    fn byte_by_byte() {
+        let mut framer = Framer::new();
+        let input = [packet(&GOOD), packet(&[0x05, 0x01, 0x07])].concat();
+        let mut frames = Vec::new();
+        for b in input.iter() {
+            frames.extend(framer.push(&[*b]));
+        }
+        assert_eq!(frames, vec![packet(&GOOD), packet(&[0x05, 0x01, 0x07])]);
+        assert_eq!(framer.stats().frames_ok, 2);
+        assert_eq!(framer.stats().bytes_discarded, 0);
+    }
+
+    #[test]
+    fn preamble_in_payload() {
+        let mut framer = Framer::new();
+        let p = packet(&[0x04, 0x04, 0xaa, 0x55, 0xaa, 0x55]);
+        assert_eq!(framer.push(&p), vec![p]);
+        assert_eq!(framer.stats().resyncs, 0);
+    }
+
+    #[test]
+    fn crc_mismatch_then_next_frame() {
+        let mut framer = Framer::new();
+        let mut bad = packet(&GOOD);
+        *bad.last_mut().unwrap() ^= 0x01;
+        let frames = framer.push(&[bad.clone(), packet(&GOOD)].concat());
+        assert_eq!(frames, vec![packet(&GOOD)]);
+        let stats = framer.stats();
+        assert_eq!(stats.frames_ok, 1);
+        assert_eq!(stats.crc_failures, 1);
+        assert_eq!(stats.resyncs, 1);
+        // Searched again byte by byte, no preamble in it
+        assert_eq!(stats.bytes_discarded, bad.len() as u64);
+        assert!(stats.last_error.starts_with("checksum mismatch"));
+    }
+
+    #[test]
+    fn zero_length() {
+        let mut framer = Framer::new();
+        let frames = framer.push(&[&[0xaa, 0x55, 0x00][..], &packet(&GOOD)].concat());
+        assert_eq!(frames, vec![packet(&GOOD)]);
+        let stats = framer.stats();
+        assert_eq!(stats.resyncs, 1);
+        assert_eq!(stats.bytes_discarded, 3);
+        assert_eq!(stats.last_error, "zero length packet");
+    }
+
+    #[test]
+    fn noise_counts() {
+        let mut framer = Framer::new();
+        // A repeated first preamble and a broken second one
+        let noise = [0x00, 0x12, 0xaa, 0xaa, 0x13];
+        let frames = framer.push(&[&noise[..], &packet(&GOOD)].concat());
+        assert_eq!(frames, vec![packet(&GOOD)]);
+        let stats = framer.stats();
+        assert_eq!(stats.bytes_discarded, noise.len() as u64);
+        assert_eq!(stats.resyncs, 0);
+
+        framer.reset();
+        assert_eq!(framer.stats().bytes_discarded, 0);
+    }
+
+    #[test]
+    fn split_across_pushes() {
+        let mut framer = Framer::new();
+        let p = packet(&GOOD);
+        assert!(framer.push(&p[..4]).is_empty());
+        assert_eq!(framer.push(&p[4..]), vec![p]);
+    }
+}
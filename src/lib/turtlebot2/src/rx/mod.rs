@@ -1,12 +1,21 @@
 #![allow(unused)]
 
+mod error;
+mod flags;
+mod framer;
+
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use anyhow::{anyhow, Error, Result};
 
 use crate::api::*;
+use crate::transport::ReplayControl;
+use crate::tune::Tune;
 use crate::tx::*;
 
+pub use error::DecodeError;
+pub use framer::Framer;
+
 // Variant enum
 #[derive(Debug, Clone, PartialEq)]
 pub enum CommandId {
@@ -20,25 +29,6 @@ pub enum CommandId {
     GetControllerGain = 14,
 }
 
-// These can be used to set the length of command
-// Total length = ID + Size + Payload + CRC
-pub const CMD_LEN_BASE_CONTROL: u8 = 7;
-pub const CMD_LEN_SOUND: u8 = 6;
-pub const CMD_LEN_SOUND_SEQUENCE: u8 = 4;
-pub const CMD_LEN_REQUEST_EXTRA: u8 = 5;
-pub const CMD_LEN_GENERAL_PURPOSE_OUTPUT: u8 = 5;
-pub const CMD_LEN_SET_CONTROLLER_GAIN: u8 = 16;
-pub const CMD_LEN_GET_CONTROLLER_GAIN: u8 = 4;
-
-// These can be used to set the size of payload
-pub const CMD_SIZE_BASE_CONTROL: u8 = 4;
-pub const CMD_SIZE_SOUND: u8 = 3;
-pub const CMD_SIZE_SOUND_SEQUENCE: u8 = 1;
-pub const CMD_SIZE_REQUEST_EXTRA: u8 = 2;
-pub const CMD_SIZE_GENERAL_PURPOSE_OUTPUT: u8 = 2;
-pub const CMD_SIZE_SET_CONTROLLER_GAIN: u8 = 13;
-pub const CMD_SIZE_GET_CONTROLLER_GAIN: u8 = 1;
-
 //
 #[derive(Debug, Clone)]
 pub struct Command {
@@ -46,6 +36,13 @@ pub struct Command {
     pub serial_command: String,
     pub serial_port_name: String,
     pub payload: Vec<u8>,
+    pub reconnect_policy: ReconnectPolicy,
+    pub event_sound_policy: EventSoundPolicy,
+    pub connection_config: ConnectionConfig,
+    pub capture_path: String,
+    pub replay_control: Option<ReplayControl>,
+    pub tune: Option<Tune>,
+    pub tempo_bpm: f64,
 }
 
 impl Command {
@@ -55,297 +52,263 @@ impl Command {
             serial_command: "".to_string(),
             serial_port_name: "".to_string(),
             payload: Vec::new(),
+            reconnect_policy: ReconnectPolicy::default(),
+            event_sound_policy: EventSoundPolicy::default(),
+            connection_config: ConnectionConfig::default(),
+            capture_path: "".to_string(),
+            replay_control: None,
+            tune: None,
+            tempo_bpm: 0.0,
         }
     }
 }
 
-// decode (buffer => packets => feedbacks)
-pub fn decode(buffer: &[u8], mut residue: &[u8]) -> Result<(Vec<Feedback>, Vec<u8>)> {
-    // Check if the length if enough.
-    // The min length is 70.
-    let len = buffer.len();
-    if len < 81 {
-        return Err(anyhow!("Not enough data"));
-    }
-
+// decode (bytes => packets by the framer => feedbacks)
+// The framer keeps the packet that was cut by the previous read,
+// so a packet is decoded as soon as its last byte arrives.
+// Errors don't stop decoding, but are counted in the framer's stats.
+pub fn decode(framer: &mut Framer, buffer: &[u8]) -> Vec<Feedback> {
     let mut feedbacks = Vec::new();
-    let mut new_residue = Vec::new();
-
-    // Search for the preambles (0xaa, 0x55)
-    let headers = search_header(&buffer[..len])?; // Headers not found
-
-    // If the first preambles set is not located at 0 of the buffer, there is a broken packet
-    // The residue from previous iteration and the broken packet
-    // should be used to make a complete packet
-    if headers[0] != 0 && residue.len() != 0 {
-        let broken_packet = &buffer[..headers[0]];
-        let merged_packet = merge_residue(&residue, broken_packet)?; // Merged failed
-        let correct_crc = check_crc(&merged_packet);
-        if correct_crc {
-            let f = format_feedback(&merged_packet)?; // Formatting failed
-            feedbacks.push(f);
-        }
-    }
-
-    // Divide packets by header found
-    let packets = divide_packet(&buffer[..len], &headers)?; // Packets not found
-    for (i, packet) in packets.iter().enumerate() {
-        // Check CRC and set the residue to pass to next iteration.
-        let correct_crc = check_crc(&packet.clone());
-        if correct_crc {
-            let f = format_feedback(packet)?; // Formatting failed
-            feedbacks.push(f);
-            new_residue = [0u8; 0].to_vec(); // Clear so don't pass to the next iteration
-        } else {
-            new_residue = packet.clone(); // Pass to the next iteration
-        }
-    }
-    return Ok((feedbacks, new_residue));
-}
-
-fn search_header(buffer: &[u8]) -> Result<Vec<usize>> {
-    let mut h = Vec::new();
-    let buf = buffer.iter();
-    // Need to skip the first byte since this loop accesses [index-1]
-    for (i, c) in buf.enumerate().skip(1) {
-        if buffer[i - 1] == 0xaa && buffer[i] == 0x55 {
-            h.push(i - 1);
-        }
-    }
-    Ok(h)
-}
-
-fn merge_residue(residue: &[u8], broken_packet: &[u8]) -> Result<Vec<u8>> {
-    let mut a = residue.clone().to_vec();
-    let b = broken_packet.to_vec();
-    a.extend(b);
-    Ok(a)
-}
-
-fn divide_packet(buffer: &[u8], headers: &[usize]) -> Result<Vec<Vec<u8>>> {
-    let mut packets = Vec::new();
-    let mut start = 0;
-    let mut end = 0;
-    for (i, c) in headers.iter().enumerate() {
-        // Use the indixes from h
-        // Or use bytes until the end if currently last part
-        if i + 1 != headers.len() {
-            start = headers[i];
-            end = headers[i + 1];
-        } else {
-            start = headers[i];
-            end = buffer.len(); // Until the end => residue
+    for packet in framer.push(buffer).iter() {
+        let (f, errors) = format_feedback(packet);
+        for e in errors.iter() {
+            framer.record(e);
         }
-        let b = buffer[start..end].to_vec().clone();
-        packets.push(b);
+        feedbacks.push(f);
     }
-    Ok(packets)
+    feedbacks
 }
 
-fn check_crc(packet: &Vec<u8>) -> bool {
-    // Don't even have preambles and total length.
-    let packet_len = packet.len();
-    if packet_len < 3 {
-        return false;
-    }
-
-    // if given packet length is NOT same as
-    // the total_len + preambles (2) + length byte (1) + checksum byte (1),
-    // it is broken packet
-    let total_len = packet[2].clone() as usize;
-    if packet_len != total_len + 4 {
-        return false;
-    }
-
-    let last = packet.len() - 1;
-    let checksum = packet.as_slice()[last];
-    let mut acc: u8 = 0;
-
-    for (i, c) in packet.iter().enumerate().skip(2) {
-        if i == last {
-            break;
-        }
-        acc = acc ^ c;
-    }
-    acc == checksum
+// A packet as the robot sends it: 0xaa | 0x55 | length | sub-payloads | checksum
+#[cfg(test)]
+pub fn packet(sub_payloads: &[u8]) -> Vec<u8> {
+    let mut p = vec![0xaa, 0x55, sub_payloads.len() as u8];
+    p.extend(sub_payloads);
+    p.push(p[2..].iter().fold(0, |acc, b| acc ^ b));
+    p
 }
 
-fn format_feedback(packet: &Vec<u8>) -> Result<Feedback> {
-    let total_len = packet[2].clone();
-    let mut exit_count = 0;
-    let mut index: u8 = 3; // assign the index of first ID of a feedback
+// The errors are about sub-payloads that couldn't be read (the rest of the feedback is still valid)
+// Each sub-payload is id (1) + size (1) + data (size), so the walker can skip what it doesn't know.
+fn format_feedback(packet: &[u8]) -> (Feedback, Vec<DecodeError>) {
     let mut f = Feedback::new();
+    let mut errors = Vec::new();
+    // The sub-payloads are between the length byte and the checksum
+    let end = (packet[2] as usize + 3).min(packet.len().saturating_sub(1));
+    let mut index = 3; // assign the index of first ID of a feedback
 
     f.epoch_time_stamp = get_epoch_ms();
 
-    loop {
-        // There are only 11 entries in FeedBackId.
-        exit_count += 1;
-        if exit_count > 11 {
+    while index < end {
+        // The id, size and data should be in the packet
+        let available = end - index;
+        let declared = if available >= 2 {
+            packet[index + 1] as usize + 2
+        } else {
+            2
+        };
+        if declared > available {
+            errors.push(DecodeError::TruncatedSubPayload {
+                id: packet[index],
+                declared,
+                available,
+            });
             break;
         }
-        // To prevent Our of Range access
-        if index >= total_len {
-            break;
+        let raw_id = packet[index];
+        let d = &packet[index + 2..index + declared];
+        index += declared;
+
+        let id: Option<FeedbackId> = num::FromPrimitive::from_u8(raw_id);
+        let id = match id {
+            Some(id) => id,
+            // Newer firmware can send what we don't know yet
+            None => {
+                errors.push(DecodeError::UnknownId(raw_id));
+                f.unknown_sub_payloads.push(UnknownSubPayload {
+                    id: raw_id,
+                    data: d.to_vec(),
+                });
+                continue;
+            }
+        };
+        // Shorter than the spec says, so the fields can't be read (skipped by the size)
+        let required = fdb_size(&id) as usize;
+        if d.len() < required {
+            errors.push(DecodeError::TruncatedSubPayload {
+                id: raw_id,
+                declared: required + 2,
+                available: declared,
+            });
+            continue;
         }
 
-        let id = num::FromPrimitive::from_u8(packet[index as usize]);
         match id {
-            Some(FeedbackId::BasicSensor) => {
+            FeedbackId::BasicSensor => {
                 f.basic_sensor.valid = true;
-                f.basic_sensor.time_stamp = packet[2 + index as usize] as u32;
-                f.basic_sensor.time_stamp |= (packet[3 + index as usize] as u32) << 8;
-                f.basic_sensor.bumper = packet[4 + index as usize] as u32;
-                f.basic_sensor.wheel_drop = packet[5 + index as usize] as u32;
-                f.basic_sensor.cliff = packet[6 + index as usize] as u32;
-                f.basic_sensor.left_encoder = packet[7 + index as usize] as u32;
-                f.basic_sensor.left_encoder |= (packet[8 + index as usize] as u32) << 8;
-                f.basic_sensor.right_encoder = packet[9 + index as usize] as u32;
-                f.basic_sensor.right_encoder |= (packet[10 + index as usize] as u32) << 8;
-                f.basic_sensor.left_pwm = packet[11 + index as usize] as u32;
-                f.basic_sensor.right_pwm = packet[12 + index as usize] as u32;
-                f.basic_sensor.button = packet[13 + index as usize] as u32;
-                f.basic_sensor.charger = packet[14 + index as usize] as u32;
-                f.basic_sensor.battery = packet[15 + index as usize] as u32;
-                f.basic_sensor.overcurrent_flags = packet[16 + index as usize] as u32;
-                index += FDB_SIZE_BASIC_SENSOR_DATA + 2;
+                f.basic_sensor.time_stamp = read_u16(d, 0) as u32;
+                f.basic_sensor.bumper = d[2] as u32;
+                f.basic_sensor.wheel_drop = d[3] as u32;
+                f.basic_sensor.cliff = d[4] as u32;
+                f.basic_sensor.left_encoder = read_u16(d, 5) as u32;
+                f.basic_sensor.right_encoder = read_u16(d, 7) as u32;
+                f.basic_sensor.left_pwm = d[9] as u32;
+                f.basic_sensor.right_pwm = d[10] as u32;
+                f.basic_sensor.button = d[11] as u32;
+                f.basic_sensor.charger = d[12] as u32;
+                f.basic_sensor.battery = d[13];
+                f.basic_sensor.battery_volts =
+                    f.basic_sensor.battery as f64 * FDB_UNIT_BATTERY_VOLTS;
+                f.basic_sensor.overcurrent_flags = d[14] as u32;
+                f.basic_sensor.bumpers = d[2].into();
+                f.basic_sensor.wheel_drops = d[3].into();
+                f.basic_sensor.cliffs = d[4].into();
+                f.basic_sensor.buttons = d[11].into();
+                f.basic_sensor.charger_state = d[12].into();
+                f.basic_sensor.overcurrents = d[14].into();
             }
-            Some(FeedbackId::DockingIR) => {
+            FeedbackId::DockingIR => {
                 f.docking_ir.valid = true;
-                f.docking_ir.right_signal = packet[2 + index as usize] as u32;
-                f.docking_ir.central_signal = packet[3 + index as usize] as u32;
-                f.docking_ir.left_signal = packet[4 + index as usize] as u32;
-                index += FDB_SIZE_DOCKING_IR + 2;
+                f.docking_ir.right_signal = d[0] as u32;
+                f.docking_ir.central_signal = d[1] as u32;
+                f.docking_ir.left_signal = d[2] as u32;
             }
-            Some(FeedbackId::InertialSensor) => {
+            FeedbackId::InertialSensor => {
                 f.inertial_sensor.valid = true;
-                f.inertial_sensor.angle = packet[2 + index as usize] as u32;
-                f.inertial_sensor.angle |= (packet[3 + index as usize] as u32) << 8;
-                f.inertial_sensor.angle_rate = packet[4 + index as usize] as u32;
-                f.inertial_sensor.angle_rate |= (packet[5 + index as usize] as u32) << 8;
-                index += FDB_SIZE_INERTIAL_SENSOR + 2;
+                let i = &mut f.inertial_sensor;
+                i.angle = read_i16(d, 0);
+                i.angle_rate = read_i16(d, 2);
+                i.angle_deg = i.angle as f64 * FDB_UNIT_INERTIAL_DEG;
+                i.angle_rad = i.angle_deg.to_radians();
+                i.angle_rate_deg_per_sec = i.angle_rate as f64 * FDB_UNIT_INERTIAL_DEG;
+                i.angle_rate_rad_per_sec = i.angle_rate_deg_per_sec.to_radians();
             }
-            Some(FeedbackId::Cliff) => {
+            FeedbackId::Cliff => {
                 f.cliff.valid = true;
-                f.cliff.right_cliff_sensor = packet[2 + index as usize] as u32;
-                f.cliff.right_cliff_sensor |= (packet[3 + index as usize] as u32) << 8;
-                f.cliff.central_cliff_sensor = packet[4 + index as usize] as u32;
-                f.cliff.central_cliff_sensor |= (packet[5 + index as usize] as u32) << 8;
-                f.cliff.left_cliff_sensor = packet[6 + index as usize] as u32;
-                f.cliff.left_cliff_sensor |= (packet[7 + index as usize] as u32) << 8;
-                index += FDB_SIZE_CLIFF + 2;
+                f.cliff.right_cliff_sensor = read_u16(d, 0) as u32;
+                f.cliff.central_cliff_sensor = read_u16(d, 2) as u32;
+                f.cliff.left_cliff_sensor = read_u16(d, 4) as u32;
             }
-            Some(FeedbackId::Current) => {
+            FeedbackId::Current => {
                 f.current.valid = true;
-                f.current.left_motor = packet[2 + index as usize] as u32;
-                f.current.right_motor = packet[3 + index as usize] as u32;
-                index += FDB_SIZE_CURRENT + 2;
+                f.current.left_motor = d[0];
+                f.current.right_motor = d[1];
+                f.current.left_motor_amps = f.current.left_motor as f64 * FDB_UNIT_CURRENT_AMPS;
+                f.current.right_motor_amps = f.current.right_motor as f64 * FDB_UNIT_CURRENT_AMPS;
             }
-            Some(FeedbackId::HardwareVersion) => {
+            FeedbackId::HardwareVersion => {
                 f.hardware_version.valid = true;
-                f.hardware_version.patch = packet[2 + index as usize] as u32;
-                f.hardware_version.minor = packet[3 + index as usize] as u32;
-                f.hardware_version.major = packet[4 + index as usize] as u32;
-                index += FDB_SIZE_HARDWARE_VERSION + 2;
+                f.hardware_version.patch = d[0] as u32;
+                f.hardware_version.minor = d[1] as u32;
+                f.hardware_version.major = d[2] as u32;
             }
-            Some(FeedbackId::FirmwareVersion) => {
+            FeedbackId::FirmwareVersion => {
                 f.firmware_version.valid = true;
-                f.firmware_version.patch = packet[2 + index as usize] as u32;
-                f.firmware_version.minor = packet[3 + index as usize] as u32;
-                f.firmware_version.major = packet[4 + index as usize] as u32;
-                index += FDB_SIZE_FIRMWARE_VERSION + 2;
+                f.firmware_version.patch = d[0] as u32;
+                f.firmware_version.minor = d[1] as u32;
+                f.firmware_version.major = d[2] as u32;
             }
-            Some(FeedbackId::RawDataOf3AxisGyro) => {
+            FeedbackId::RawDataOf3AxisGyro => {
                 f.gyro.valid = true;
-                f.gyro.frame_id = packet[2 + index as usize] as u32;
-                f.gyro.followed_data_length = packet[3 + index as usize] as u32;
-                //
-                f.gyro.x0 = packet[4 + index as usize] as u32;
-                f.gyro.x0 |= (packet[5 + index as usize] as u32) << 8;
-                f.gyro.y0 = packet[6 + index as usize] as u32;
-                f.gyro.y0 |= (packet[7 + index as usize] as u32) << 8;
-                f.gyro.z0 = packet[8 + index as usize] as u32;
-                f.gyro.z0 |= (packet[9 + index as usize] as u32) << 8;
-                //
-                f.gyro.x1 = packet[4 + index as usize] as u32;
-                f.gyro.x1 |= (packet[5 + index as usize] as u32) << 8;
-                f.gyro.y1 = packet[6 + index as usize] as u32;
-                f.gyro.y1 |= (packet[7 + index as usize] as u32) << 8;
-                f.gyro.z1 = packet[8 + index as usize] as u32;
-                f.gyro.z1 |= (packet[9 + index as usize] as u32) << 8;
-                //
-                if packet[1 + index as usize] == FDB_SIZE_RAW_DATA_3_AXIS_GYRO_A {
-                    index += FDB_SIZE_RAW_DATA_3_AXIS_GYRO_A + 2;
-                } else if packet[1 + index as usize] == FDB_SIZE_RAW_DATA_3_AXIS_GYRO_B {
-                    f.gyro.x2 = packet[4 + index as usize] as u32;
-                    f.gyro.x2 |= (packet[5 + index as usize] as u32) << 8;
-                    f.gyro.y2 = packet[6 + index as usize] as u32;
-                    f.gyro.y2 |= (packet[7 + index as usize] as u32) << 8;
-                    f.gyro.z2 = packet[8 + index as usize] as u32;
-                    f.gyro.z2 |= (packet[9 + index as usize] as u32) << 8;
-                    index += FDB_SIZE_RAW_DATA_3_AXIS_GYRO_B + 2;
+                f.gyro.frame_id = d[0] as u32;
+                // The number of values (3 per sample)
+                f.gyro.followed_data_length = d[1] as u32;
+                // Trust the size of the sub-payload over the followed length
+                let sample = FDB_SIZE_RAW_DATA_3_AXIS_GYRO_SAMPLE as usize;
+                let header = FDB_SIZE_RAW_DATA_3_AXIS_GYRO_HEADER as usize;
+                let n = (f.gyro.followed_data_length as usize / 3).min((d.len() - header) / sample);
+                for k in 0..n {
+                    let at = header + k * sample;
+                    f.gyro.samples.push(gyro_sample(
+                        f.gyro.frame_id,
+                        read_i16(d, at),
+                        read_i16(d, at + 2),
+                        read_i16(d, at + 4),
+                    ));
                 }
+                gyro_rates(&mut f.gyro);
             }
-            Some(FeedbackId::GeneralPurposeInput) => {
+            FeedbackId::GeneralPurposeInput => {
                 f.general_purpose_input.valid = true;
-                f.general_purpose_input.d_ch0 = packet[2 + index as usize] as u32;
-                f.general_purpose_input.d_ch0 |= (packet[3 + index as usize] as u32) << 8;
-                //
-                f.general_purpose_input.a_ch0 = packet[4 + index as usize] as u32;
-                f.general_purpose_input.a_ch0 |= (packet[5 + index as usize] as u32) << 8;
-                //
-                f.general_purpose_input.a_ch1 = packet[6 + index as usize] as u32;
-                f.general_purpose_input.a_ch1 |= (packet[7 + index as usize] as u32) << 8;
-                //
-                f.general_purpose_input.a_ch2 = packet[8 + index as usize] as u32;
-                f.general_purpose_input.a_ch2 |= (packet[9 + index as usize] as u32) << 8;
-                //
-                f.general_purpose_input.a_ch3 = packet[10 + index as usize] as u32;
-                f.general_purpose_input.a_ch3 |= (packet[11 + index as usize] as u32) << 8;
-                index += FDB_SIZE_GENERAL_PURPOSE_OUTPUT + 2;
+                f.general_purpose_input.d_ch0 = read_u16(d, 0) as u32;
+                f.general_purpose_input.a_ch0 = read_u16(d, 2) as u32;
+                f.general_purpose_input.a_ch1 = read_u16(d, 4) as u32;
+                f.general_purpose_input.a_ch2 = read_u16(d, 6) as u32;
+                f.general_purpose_input.a_ch3 = read_u16(d, 8) as u32;
             }
-            Some(FeedbackId::UniqueDeviceId) => {
+            FeedbackId::UniqueDeviceId => {
                 f.unique_device_id.valid = true;
-                f.unique_device_id.udid0 = packet[2 + index as usize] as u32;
-                f.unique_device_id.udid0 |= (packet[3 + index as usize] as u32) << 8;
-                f.unique_device_id.udid0 |= (packet[4 + index as usize] as u32) << 16;
-                f.unique_device_id.udid0 |= (packet[5 + index as usize] as u32) << 24;
-                //
-                f.unique_device_id.udid1 = packet[6 + index as usize] as u32;
-                f.unique_device_id.udid1 |= (packet[7 + index as usize] as u32) << 8;
-                f.unique_device_id.udid1 |= (packet[8 + index as usize] as u32) << 16;
-                f.unique_device_id.udid1 |= (packet[9 + index as usize] as u32) << 24;
-                //
-                f.unique_device_id.udid2 = packet[10 + index as usize] as u32;
-                f.unique_device_id.udid2 |= (packet[11 + index as usize] as u32) << 8;
-                f.unique_device_id.udid2 |= (packet[12 + index as usize] as u32) << 16;
-                f.unique_device_id.udid2 |= (packet[13 + index as usize] as u32) << 24;
-                index += FDB_SIZE_UNIQUE_DEVICE_IDENTIFIER + 2;
+                f.unique_device_id.udid0 = read_u32(d, 0);
+                f.unique_device_id.udid1 = read_u32(d, 4);
+                f.unique_device_id.udid2 = read_u32(d, 8);
             }
-            Some(FeedbackId::ControllerInfo) => {
+            FeedbackId::ControllerInfo => {
                 f.controller_info.valid = true;
-                f.controller_info.p_gain = packet[2 + index as usize] as u32;
-                f.controller_info.p_gain |= (packet[3 + index as usize] as u32) << 8;
-                f.controller_info.p_gain |= (packet[4 + index as usize] as u32) << 16;
-                f.controller_info.p_gain |= (packet[5 + index as usize] as u32) << 24;
-                //
-                f.controller_info.i_gain = packet[6 + index as usize] as u32;
-                f.controller_info.i_gain |= (packet[7 + index as usize] as u32) << 8;
-                f.controller_info.i_gain |= (packet[8 + index as usize] as u32) << 16;
-                f.controller_info.i_gain |= (packet[9 + index as usize] as u32) << 24;
-                //
-                f.controller_info.d_gain = packet[10 + index as usize] as u32;
-                f.controller_info.d_gain |= (packet[11 + index as usize] as u32) << 8;
-                f.controller_info.d_gain |= (packet[12 + index as usize] as u32) << 16;
-                f.controller_info.d_gain |= (packet[13 + index as usize] as u32) << 24;
-                index += FDB_SIZE_CONTROLLER_INFO + 2;
-            }
-            _ => {
-                // Nothing to do
+                // 0: factory default, 1: user configured
+                f.controller_info.is_user_configured = d[0] as u32;
+                f.controller_info.p_gain = read_u32(d, 1);
+                f.controller_info.i_gain = read_u32(d, 5);
+                f.controller_info.d_gain = read_u32(d, 9);
             }
         }
     }
-    Ok(f)
+    (f, errors)
+}
+
+// The least size of each sub-payload
+fn fdb_size(id: &FeedbackId) -> u8 {
+    match id {
+        FeedbackId::BasicSensor => FDB_SIZE_BASIC_SENSOR_DATA,
+        FeedbackId::DockingIR => FDB_SIZE_DOCKING_IR,
+        FeedbackId::InertialSensor => FDB_SIZE_INERTIAL_SENSOR,
+        FeedbackId::Cliff => FDB_SIZE_CLIFF,
+        FeedbackId::Current => FDB_SIZE_CURRENT,
+        FeedbackId::HardwareVersion => FDB_SIZE_HARDWARE_VERSION,
+        FeedbackId::FirmwareVersion => FDB_SIZE_FIRMWARE_VERSION,
+        FeedbackId::RawDataOf3AxisGyro => FDB_SIZE_RAW_DATA_3_AXIS_GYRO_HEADER,
+        FeedbackId::GeneralPurposeInput => FDB_SIZE_GENERAL_PURPOSE_INPUT,
+        FeedbackId::UniqueDeviceId => FDB_SIZE_UNIQUE_DEVICE_IDENTIFIER,
+        FeedbackId::ControllerInfo => FDB_SIZE_CONTROLLER_INFO,
+    }
+}
+
+// Little endian
+fn read_u16(d: &[u8], i: usize) -> u16 {
+    u16::from_le_bytes([d[i], d[i + 1]])
+}
+
+// Little endian, signed
+fn read_i16(d: &[u8], i: usize) -> i16 {
+    i16::from_le_bytes([d[i], d[i + 1]])
+}
+
+fn read_u32(d: &[u8], i: usize) -> u32 {
+    u32::from_le_bytes([d[i], d[i + 1], d[i + 2], d[i + 3]])
+}
+
+fn gyro_sample(frame_id: u32, x: i16, y: i16, z: i16) -> GyroSample {
+    let rad_per_sec = |d: i16| (d as f64 * FDB_UNIT_GYRO_DEG_PER_SEC).to_radians();
+    GyroSample {
+        frame_id,
+        x,
+        y,
+        z,
+        x_rad_per_sec: rad_per_sec(x),
+        y_rad_per_sec: rad_per_sec(y),
+        z_rad_per_sec: rad_per_sec(z),
+    }
+}
+
+// Averages the samples
+fn gyro_rates(g: &mut Gyro) {
+    let n = g.samples.len();
+    if n == 0 {
+        return;
+    }
+    let mean = |v: f64| v / n as f64;
+    g.x_rad_per_sec = mean(g.samples.iter().map(|s| s.x_rad_per_sec).sum());
+    g.y_rad_per_sec = mean(g.samples.iter().map(|s| s.y_rad_per_sec).sum());
+    g.z_rad_per_sec = mean(g.samples.iter().map(|s| s.z_rad_per_sec).sum());
 }
 
 // https://stackoverflow.com/a/65051530
@@ -356,3 +319,158 @@ fn get_epoch_ms() -> String {
         .as_millis()
         .to_string()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // id | size | data
+    fn sub_payload(id: FeedbackId, data: &[u8]) -> Vec<u8> {
+        let mut p = vec![id as u8, data.len() as u8];
+        p.extend(data);
+        p
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() < 1e-9, "{}", actual);
+    }
+
+    #[test]
+    fn units() {
+        let mut basic = vec![0; FDB_SIZE_BASIC_SENSOR_DATA as usize];
+        basic[13] = 165;
+        let mut sub_payloads = sub_payload(FeedbackId::BasicSensor, &basic);
+        // Angle -100 (-1.0 deg) and angle rate 150 (1.5 deg/s), then 3 unused bytes
+        let inertial = [0x9c, 0xff, 0x96, 0x00, 0, 0, 0];
+        sub_payloads.extend(sub_payload(FeedbackId::InertialSensor, &inertial));
+        // 10 mA per unit
+        sub_payloads.extend(sub_payload(FeedbackId::Current, &[50, 7]));
+
+        let (f, errors) = format_feedback(&packet(&sub_payloads));
+        assert!(errors.is_empty());
+
+        let i = &f.inertial_sensor;
+        assert!(i.valid);
+        assert_eq!(i.angle, -100);
+        assert_close(i.angle_deg, -1.0);
+        assert_close(i.angle_rad, -1.0_f64.to_radians());
+        assert_eq!(i.angle_rate, 150);
+        assert_close(i.angle_rate_deg_per_sec, 1.5);
+
+        assert!(f.current.valid);
+        assert_close(f.current.left_motor_amps, 0.5);
+        assert_close(f.current.right_motor_amps, 0.07);
+
+        assert_eq!(f.basic_sensor.battery, 165);
+        assert_close(f.basic_sensor.battery_volts, 16.5);
+    }
+
+    fn gyro(frame_id: u8, followed_data_length: u8, samples: &[[i16; 3]]) -> Vec<u8> {
+        let mut d = vec![frame_id, followed_data_length];
+        for s in samples.iter() {
+            for v in s.iter() {
+                d.extend(v.to_le_bytes());
+            }
+        }
+        sub_payload(FeedbackId::RawDataOf3AxisGyro, &d)
+    }
+
+    #[test]
+    fn gyro_samples() {
+        let samples = [[1, -2, 3], [400, -500, 600], [-7000, 8000, -9000]];
+        let (f, errors) = format_feedback(&packet(&gyro(42, 9, &samples)));
+        assert!(errors.is_empty());
+        assert!(f.gyro.valid);
+        assert_eq!(f.gyro.frame_id, 42);
+        assert_eq!(f.gyro.followed_data_length, 9);
+        let found: Vec<(u32, i16, i16, i16)> = f
+            .gyro
+            .samples
+            .iter()
+            .map(|s| (s.frame_id, s.x, s.y, s.z))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (42, 1, -2, 3),
+                (42, 400, -500, 600),
+                (42, -7000, 8000, -9000)
+            ]
+        );
+        assert_close(
+            f.gyro.samples[1].x_rad_per_sec,
+            (400.0 * FDB_UNIT_GYRO_DEG_PER_SEC).to_radians(),
+        );
+    }
+
+    #[test]
+    fn gyro_shorter_than_followed_data_length() {
+        // 3 samples claimed, 2 sent
+        let samples = [[1, 2, 3], [4, 5, 6]];
+        let (f, errors) = format_feedback(&packet(&gyro(7, 9, &samples)));
+        assert!(errors.is_empty());
+        assert_eq!(f.gyro.followed_data_length, 9);
+        let z: Vec<i16> = f.gyro.samples.iter().map(|s| s.z).collect();
+        assert_eq!(z, vec![3, 6]);
+    }
+
+    #[test]
+    fn unknown_id_is_skipped() {
+        let mut sub_payloads = vec![0x63, 3, 0xaa, 0x55, 0x01];
+        sub_payloads.extend(sub_payload(FeedbackId::Current, &[10, 20]));
+        let (f, errors) = format_feedback(&packet(&sub_payloads));
+        assert_eq!(errors, vec![DecodeError::UnknownId(0x63)]);
+        assert_eq!(f.unknown_sub_payloads.len(), 1);
+        assert_eq!(f.unknown_sub_payloads[0].id, 0x63);
+        assert_eq!(f.unknown_sub_payloads[0].data, vec![0xaa, 0x55, 0x01]);
+        // The next one is still decoded
+        assert!(f.current.valid);
+        assert_eq!((f.current.left_motor, f.current.right_motor), (10, 20));
+    }
+
+    #[test]
+    fn size_past_the_frame() {
+        let mut sub_payloads = sub_payload(FeedbackId::Current, &[10, 20]);
+        // 10 bytes declared, 2 in the frame
+        sub_payloads.extend([FeedbackId::Cliff as u8, 10, 1, 2]);
+        let (f, errors) = format_feedback(&packet(&sub_payloads));
+        assert_eq!(
+            errors,
+            vec![DecodeError::TruncatedSubPayload {
+                id: FeedbackId::Cliff as u8,
+                declared: 12,
+                available: 4,
+            }]
+        );
+        assert!(f.current.valid);
+        assert!(!f.cliff.valid);
+
+        // Only the id is left
+        let (_, errors) = format_feedback(&packet(&[FeedbackId::Cliff as u8]));
+        assert_eq!(
+            errors,
+            vec![DecodeError::TruncatedSubPayload {
+                id: FeedbackId::Cliff as u8,
+                declared: 2,
+                available: 1,
+            }]
+        );
+    }
+
+    #[test]
+    fn known_id_shorter_than_spec() {
+        // The inertial sensor needs 7 bytes
+        let mut sub_payloads = sub_payload(FeedbackId::InertialSensor, &[0x9c, 0xff]);
+        sub_payloads.extend(sub_payload(FeedbackId::Current, &[10, 20]));
+        let mut framer = Framer::new();
+        let f = decode(&mut framer, &packet(&sub_payloads));
+        assert_eq!(f.len(), 1);
+        assert!(!f[0].inertial_sensor.valid);
+        assert!(f[0].current.valid);
+
+        let stats = framer.stats();
+        assert_eq!(stats.frames_ok, 1);
+        assert_eq!(stats.truncated_sub_payloads, 1);
+        assert_eq!(stats.unknown_ids, 0);
+    }
+}
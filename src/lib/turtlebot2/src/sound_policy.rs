@@ -0,0 +1,201 @@
+use crate::api::{BatteryState, ChargerState, EventSoundPolicy, Feedback, SoundSequence};
+
+// The charge has to come back this much over the threshold to warn again
+const LOW_BATTERY_HYSTERESIS: f64 = 5.0;
+
+// EventSounds decides which sound sequence to play by the EventSoundPolicy.
+// An event plays on its rising edge (e.g. a bumper pressed), not while it lasts.
+// Should be fed every feedback of a connection in order.
+pub struct EventSounds {
+    policy: EventSoundPolicy,
+    bumped: bool,
+    low_battery: bool,
+    // None until the first basic sensor data (docked at the start is not docking)
+    docked: Option<bool>,
+}
+
+impl EventSounds {
+    pub fn new(policy: EventSoundPolicy) -> EventSounds {
+        EventSounds {
+            policy,
+            bumped: false,
+            low_battery: false,
+            docked: None,
+        }
+    }
+
+    // The state of the events is kept
+    pub fn set_policy(&mut self, policy: EventSoundPolicy) {
+        self.policy = policy;
+    }
+
+    // For a new connection
+    pub fn reset(&mut self) {
+        self.bumped = false;
+        self.low_battery = false;
+        self.docked = None;
+    }
+
+    pub fn connected(&self) -> Option<SoundSequence> {
+        let p = &self.policy;
+        if p.enabled && p.on_connected {
+            Some(p.connected)
+        } else {
+            None
+        }
+    }
+
+    // The sequences to play for this feedback
+    pub fn update(&mut self, f: &Feedback) -> Vec<SoundSequence> {
+        let mut sounds = Vec::new();
+        if !f.basic_sensor.valid {
+            return sounds;
+        }
+        let p = &self.policy;
+        let s = &f.basic_sensor;
+
+        let bumped = s.bumpers.left || s.bumpers.center || s.bumpers.right;
+        if bumped && !self.bumped && p.on_bump {
+            sounds.push(p.bump);
+        }
+        self.bumped = bumped;
+
+        let docked = matches!(
+            s.charger_state,
+            ChargerState::DockingCharging | ChargerState::DockingCharged
+        );
+        if docked && self.docked == Some(false) && p.on_docking {
+            sounds.push(p.docking);
+        }
+        self.docked = Some(docked);
+
+        if f.battery.valid {
+            let discharging = f.battery.state == BatteryState::Discharging;
+            let percentage = f.battery.percentage;
+            if discharging && percentage < p.low_battery_percentage {
+                if !self.low_battery && p.on_low_battery {
+                    sounds.push(p.low_battery);
+                }
+                self.low_battery = true;
+            } else if !discharging || percentage > p.low_battery_percentage + LOW_BATTERY_HYSTERESIS
+            {
+                self.low_battery = false;
+            }
+        }
+
+        if !p.enabled {
+            sounds.clear();
+        }
+        sounds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn enabled() -> EventSoundPolicy {
+        EventSoundPolicy {
+            enabled: true,
+            ..Default::default()
+        }
+    }
+
+    fn feedback(bumped: bool, charger_state: ChargerState) -> Feedback {
+        let mut f = Feedback::new();
+        f.basic_sensor.valid = true;
+        f.basic_sensor.bumpers.center = bumped;
+        f.basic_sensor.charger_state = charger_state;
+        f
+    }
+
+    fn battery(percentage: f64, state: BatteryState) -> Feedback {
+        let mut f = feedback(false, ChargerState::Discharging);
+        f.battery.valid = true;
+        f.battery.percentage = percentage;
+        f.battery.state = state;
+        f
+    }
+
+    #[test]
+    fn bump_on_rising_edge() {
+        let mut sounds = EventSounds::new(enabled());
+        let released = feedback(false, ChargerState::Discharging);
+        let pressed = feedback(true, ChargerState::Discharging);
+        assert!(sounds.update(&released).is_empty());
+        assert_eq!(sounds.update(&pressed), vec![SoundSequence::Button]);
+        // Held
+        assert!(sounds.update(&pressed).is_empty());
+        assert!(sounds.update(&released).is_empty());
+        assert_eq!(sounds.update(&pressed), vec![SoundSequence::Button]);
+    }
+
+    #[test]
+    fn docked_at_start_is_not_docking() {
+        let mut sounds = EventSounds::new(enabled());
+        let docked = feedback(false, ChargerState::DockingCharging);
+        let undocked = feedback(false, ChargerState::Discharging);
+        assert!(sounds.update(&docked).is_empty());
+        assert!(sounds.update(&docked).is_empty());
+        assert!(sounds.update(&undocked).is_empty());
+        assert_eq!(sounds.update(&docked), vec![SoundSequence::CleaningEnd]);
+
+        // Same after a reconnection
+        sounds.reset();
+        assert!(sounds.update(&docked).is_empty());
+    }
+
+    #[test]
+    fn low_battery_hysteresis() {
+        // Under 15 %, then over 20 % to warn again
+        let mut sounds = EventSounds::new(enabled());
+        let discharging = |p| battery(p, BatteryState::Discharging);
+        assert!(sounds.update(&discharging(16.0)).is_empty());
+        assert_eq!(
+            sounds.update(&discharging(14.0)),
+            vec![SoundSequence::Recharge]
+        );
+        assert!(sounds.update(&discharging(13.0)).is_empty());
+        // Within the hysteresis
+        assert!(sounds.update(&discharging(18.0)).is_empty());
+        assert!(sounds.update(&discharging(14.0)).is_empty());
+        // Over it
+        assert!(sounds.update(&discharging(21.0)).is_empty());
+        assert_eq!(
+            sounds.update(&discharging(14.0)),
+            vec![SoundSequence::Recharge]
+        );
+        // Charging clears it too
+        assert!(sounds
+            .update(&battery(14.0, BatteryState::Charging))
+            .is_empty());
+        assert_eq!(
+            sounds.update(&discharging(14.0)),
+            vec![SoundSequence::Recharge]
+        );
+    }
+
+    #[test]
+    fn disabled() {
+        let mut sounds = EventSounds::new(EventSoundPolicy::default());
+        assert_eq!(sounds.connected(), None);
+        let docked = feedback(false, ChargerState::DockingCharged);
+        assert!(sounds
+            .update(&feedback(false, ChargerState::Discharging))
+            .is_empty());
+        assert!(sounds
+            .update(&feedback(true, ChargerState::Discharging))
+            .is_empty());
+        assert!(sounds.update(&docked).is_empty());
+        assert!(sounds
+            .update(&battery(5.0, BatteryState::Discharging))
+            .is_empty());
+
+        // The events are still tracked, so enabling doesn't play what already happened
+        sounds.set_policy(enabled());
+        assert_eq!(sounds.connected(), Some(SoundSequence::On));
+        assert!(sounds
+            .update(&battery(5.0, BatteryState::Discharging))
+            .is_empty());
+    }
+}
@@ -0,0 +1,73 @@
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+    thread,
+    time::Duration,
+};
+
+use anyhow::{anyhow, Result};
+
+use crate::transport::Transport;
+
+// How long a read waits when nothing has been pushed (like a read timeout)
+const READ_WAIT: Duration = Duration::from_millis(1);
+
+// MemoryTransport is a Kobuki in memory to test the serial thread without a robot.
+// The bytes pushed to incoming are read, and every write is kept in written.
+// Clones share the buffers, so a test can keep one and give the other to the serial thread.
+#[derive(Clone, Default)]
+pub struct MemoryTransport {
+    pub incoming: Arc<Mutex<VecDeque<u8>>>,
+    pub written: Arc<Mutex<Vec<Vec<u8>>>>,
+    opened: bool,
+}
+
+impl MemoryTransport {
+    pub fn new() -> MemoryTransport {
+        MemoryTransport::default()
+    }
+
+    pub fn push(&self, bytes: &[u8]) {
+        self.incoming.lock().unwrap().extend(bytes);
+    }
+}
+
+impl Transport for MemoryTransport {
+    fn name(&self) -> String {
+        "memory".to_string()
+    }
+
+    fn open(&mut self) -> Result<()> {
+        self.opened = true;
+        Ok(())
+    }
+
+    fn read(&mut self, buffer: &mut [u8]) -> Result<usize> {
+        if !self.opened {
+            return Err(anyhow!("Not opened"));
+        }
+        let mut incoming = self.incoming.lock().unwrap();
+        if incoming.is_empty() {
+            drop(incoming);
+            thread::sleep(READ_WAIT);
+            return Ok(0);
+        }
+        let len = buffer.len().min(incoming.len());
+        for (b, i) in buffer.iter_mut().zip(incoming.drain(..len)) {
+            *b = i;
+        }
+        Ok(len)
+    }
+
+    fn write(&mut self, payload: &[u8]) -> Result<()> {
+        if !self.opened {
+            return Err(anyhow!("Not opened"));
+        }
+        self.written.lock().unwrap().push(payload.to_vec());
+        Ok(())
+    }
+
+    fn close(&mut self) {
+        self.opened = false;
+    }
+}
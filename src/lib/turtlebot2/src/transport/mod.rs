@@ -0,0 +1,81 @@
+#[cfg(test)]
+mod memory;
+mod replay;
+mod serial;
+mod tcp;
+
+use anyhow::{anyhow, Result};
+
+use crate::api::ConnectionConfig;
+
+#[cfg(test)]
+pub use memory::MemoryTransport;
+pub use replay::{ReplayControl, ReplayTransport, REPLAY_PREFIX};
+pub use serial::SerialTransport;
+pub use tcp::{TcpTransport, TCP_PREFIX};
+
+// Transport is the byte stream between the serial thread and a Kobuki.
+// The physical USB-Serial device is only one backend,
+// so anything that can carry the Kobuki byte protocol can be plugged in.
+pub trait Transport: Send {
+    // The name to report back to Flutter (port name or address)
+    fn name(&self) -> String;
+    fn open(&mut self) -> Result<()>;
+    // Returns Ok(0) if nothing arrived within the timeout
+    fn read(&mut self, buffer: &mut [u8]) -> Result<usize>;
+    // Should write the whole payload or fail
+    fn write(&mut self, payload: &[u8]) -> Result<()>;
+    fn close(&mut self);
+    // Finds the same device again before reopening (e.g. after re-plugging)
+    fn relocate(&mut self) -> Result<()> {
+        Ok(())
+    }
+    // Only a replay can be paused, sought and so on
+    fn control_replay(&mut self, _control: &ReplayControl) -> Result<()> {
+        Err(anyhow!("Not a replay: {}", self.name()))
+    }
+}
+
+impl Transport for Box<dyn Transport> {
+    fn name(&self) -> String {
+        (**self).name()
+    }
+
+    fn open(&mut self) -> Result<()> {
+        (**self).open()
+    }
+
+    fn read(&mut self, buffer: &mut [u8]) -> Result<usize> {
+        (**self).read(buffer)
+    }
+
+    fn write(&mut self, payload: &[u8]) -> Result<()> {
+        (**self).write(payload)
+    }
+
+    fn close(&mut self) {
+        (**self).close()
+    }
+
+    fn relocate(&mut self) -> Result<()> {
+        (**self).relocate()
+    }
+
+    fn control_replay(&mut self, control: &ReplayControl) -> Result<()> {
+        (**self).control_replay(control)
+    }
+}
+
+// Picks a backend for the given port name
+// - tcp://host:port => TcpTransport
+// - replay:///path/to/capture.log => ReplayTransport
+// - anything else => SerialTransport (e.g. /dev/ttyUSB0)
+pub fn new_transport(port_name: &str, config: &ConnectionConfig) -> Box<dyn Transport> {
+    if port_name.starts_with(TCP_PREFIX) {
+        return Box::new(TcpTransport::new(port_name, config));
+    }
+    if port_name.starts_with(REPLAY_PREFIX) {
+        return Box::new(ReplayTransport::new(port_name, config));
+    }
+    Box::new(SerialTransport::new(port_name, config))
+}
@@ -0,0 +1,310 @@
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, Result};
+
+use crate::api::{ConnectionConfig, ReplayMode};
+use crate::capture::*;
+use crate::rx::Framer;
+use crate::transport::Transport;
+
+// Prefix of capture files that should go to ReplayTransport
+pub const REPLAY_PREFIX: &str = "replay://";
+
+// What Flutter can do with a replay while it is open
+#[derive(Debug, Clone)]
+pub enum ReplayControl {
+    // Speed is only used by ReplayMode::Accelerated
+    Mode(ReplayMode, f64),
+    Pause(bool),
+    // From the beginning of the capture
+    Seek(u64),
+    // The next packet (ReplayMode::SingleStep)
+    Step,
+}
+
+// A Kobuki packet and when its last byte arrived
+struct Frame {
+    time_us: u64,
+    bytes: Vec<u8>,
+}
+
+// ReplayTransport plays the RX bytes of a capture file (see capture.rs) as if a Kobuki sent them.
+// The bytes are played packet by packet, so a seek or a step never cuts a packet.
+// Written commands are discarded, and the replay stays open (but silent) after the end.
+pub struct ReplayTransport {
+    address: String,
+    read_timeout: Duration,
+    frames: Vec<Frame>,
+    // The next frame to play and how much of it has been read
+    index: usize,
+    offset: usize,
+    mode: ReplayMode,
+    speed: f64,
+    paused: bool,
+    // The replay clock: position_us at the instant
+    anchor: Instant,
+    anchor_us: u64,
+    // Pending steps in ReplayMode::SingleStep
+    steps: u32,
+}
+
+impl ReplayTransport {
+    // The address should look like replay:///path/to/capture.log
+    pub fn new(address: &str, config: &ConnectionConfig) -> ReplayTransport {
+        ReplayTransport {
+            address: address.to_string(),
+            read_timeout: Duration::from_millis(config.read_timeout_ms.max(1) as u64),
+            frames: Vec::new(),
+            index: 0,
+            offset: 0,
+            mode: ReplayMode::default(),
+            speed: 1.0,
+            paused: false,
+            anchor: Instant::now(),
+            anchor_us: 0,
+            steps: 0,
+        }
+    }
+
+    fn path(&self) -> &str {
+        self.address
+            .strip_prefix(REPLAY_PREFIX)
+            .unwrap_or(&self.address)
+    }
+
+    // Where the replay is in the capture (us from the first RX bytes)
+    fn position_us(&self) -> u64 {
+        if self.paused || self.mode == ReplayMode::SingleStep {
+            return self.anchor_us;
+        }
+        let elapsed = self.anchor.elapsed().as_micros() as f64 * self.rate();
+        self.anchor_us.saturating_add(elapsed as u64)
+    }
+
+    fn rate(&self) -> f64 {
+        match self.mode {
+            ReplayMode::Accelerated if self.speed > 0.0 => self.speed,
+            _ => 1.0,
+        }
+    }
+
+    // Restarts the clock from the given position
+    fn set_anchor(&mut self, position_us: u64) {
+        self.anchor = Instant::now();
+        self.anchor_us = position_us;
+    }
+
+    // Copies the next frame (or the rest of it) to the buffer
+    fn play(&mut self, buffer: &mut [u8]) -> usize {
+        let frame = &self.frames[self.index];
+        let (time_us, frame_len) = (frame.time_us, frame.bytes.len());
+        let rest = &frame.bytes[self.offset..];
+        let len = rest.len().min(buffer.len());
+        buffer[..len].copy_from_slice(&rest[..len]);
+        self.offset += len;
+        if self.offset == frame_len {
+            self.index += 1;
+            self.offset = 0;
+            if self.mode == ReplayMode::SingleStep {
+                self.steps = self.steps.saturating_sub(1);
+                // The clock stays at the last played packet
+                self.anchor_us = time_us;
+            }
+        }
+        len
+    }
+
+    // Nothing to play for now
+    fn idle(&self, wait: Duration) -> Result<usize> {
+        thread::sleep(wait.min(self.read_timeout));
+        Ok(0)
+    }
+
+    pub fn control(&mut self, control: &ReplayControl) -> Result<()> {
+        let position = self.position_us();
+        match control {
+            ReplayControl::Mode(mode, speed) => {
+                self.mode = *mode;
+                self.speed = *speed;
+                self.steps = 0;
+            }
+            ReplayControl::Pause(paused) => {
+                self.paused = *paused;
+            }
+            ReplayControl::Seek(position_ms) => {
+                let position = position_ms.saturating_mul(1000);
+                self.index = self.frames.partition_point(|f| f.time_us < position);
+                self.offset = 0;
+                self.set_anchor(position);
+                return Ok(());
+            }
+            ReplayControl::Step => {
+                if self.mode != ReplayMode::SingleStep {
+                    return Err(anyhow!("Not in single step mode"));
+                }
+                self.steps += 1;
+            }
+        }
+        self.set_anchor(position);
+        Ok(())
+    }
+}
+
+impl Transport for ReplayTransport {
+    fn name(&self) -> String {
+        self.address.clone()
+    }
+
+    fn open(&mut self) -> Result<()> {
+        let records = read_capture(self.path())?;
+        self.frames = frames(&records);
+        if self.frames.is_empty() {
+            return Err(anyhow!("Nothing to replay in {}", self.path()));
+        }
+        self.index = 0;
+        self.offset = 0;
+        self.steps = 0;
+        self.set_anchor(0);
+        Ok(())
+    }
+
+    fn read(&mut self, buffer: &mut [u8]) -> Result<usize> {
+        // The rest of a frame that didn't fit in the buffer
+        let mut len = 0;
+        if self.offset > 0 {
+            len = self.play(buffer);
+        }
+        // Then every frame that is due, so a slow poll doesn't fall behind the capture
+        let position = self.position_us();
+        while len < buffer.len() && self.index < self.frames.len() {
+            let due = match self.mode {
+                ReplayMode::SingleStep => self.steps > 0,
+                _ => !self.paused && self.frames[self.index].time_us <= position,
+            };
+            if !due {
+                break;
+            }
+            len += self.play(&mut buffer[len..]);
+        }
+        if len > 0 {
+            return Ok(len);
+        }
+
+        // Finished (Flutter can still seek back), paused or waiting for a step
+        if self.index >= self.frames.len() || self.paused || self.mode == ReplayMode::SingleStep {
+            return self.idle(self.read_timeout);
+        }
+        let wait = (self.frames[self.index].time_us - position) as f64 / self.rate();
+        self.idle(Duration::from_micros(wait as u64))
+    }
+
+    fn write(&mut self, _payload: &[u8]) -> Result<()> {
+        Ok(())
+    }
+
+    fn close(&mut self) {
+        self.frames = Vec::new();
+    }
+
+    fn control_replay(&mut self, control: &ReplayControl) -> Result<()> {
+        self.control(control)
+    }
+}
+
+// Cuts the RX bytes into packets with the same Framer as a live port.
+// A packet gets the time of the read that completed it.
+// The bytes that don't make a packet (noise, broken packets) are not replayed.
+fn frames(records: &[CaptureRecord]) -> Vec<Frame> {
+    let start = match records.iter().find(|r| r.rx) {
+        Some(r) => r.time_us,
+        None => return Vec::new(),
+    };
+    let mut framer = Framer::new();
+    let mut frames = Vec::new();
+    for r in records.iter().filter(|r| r.rx) {
+        let time_us = r.time_us.saturating_sub(start);
+        for bytes in framer.push(&r.bytes) {
+            frames.push(Frame { time_us, bytes });
+        }
+    }
+    frames
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rx::decode;
+
+    // 3 basic sensor packets 20 ms apart (time stamps 0x10, 0x24 and 0x38),
+    // the second one split in two reads and the third one after a noise byte
+    const CAPTURE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/testdata/kobuki_capture.log");
+
+    fn open(mode: ReplayMode, speed: f64) -> ReplayTransport {
+        let mut replay = ReplayTransport::new(
+            &format!("{}{}", REPLAY_PREFIX, CAPTURE),
+            &ConnectionConfig::default(),
+        );
+        replay.open().unwrap();
+        replay.control(&ReplayControl::Mode(mode, speed)).unwrap();
+        replay
+    }
+
+    #[test]
+    fn frames_by_framer() {
+        let replay = open(ReplayMode::RealTime, 1.0);
+        let times: Vec<u64> = replay.frames.iter().map(|f| f.time_us).collect();
+        assert_eq!(times, vec![0, 21_000, 40_000]);
+        assert!(replay.frames.iter().all(|f| f.bytes.len() == 21));
+    }
+
+    #[test]
+    fn replay_through_decode() {
+        let mut replay = open(ReplayMode::Accelerated, 1_000_000.0);
+        thread::sleep(Duration::from_millis(1));
+
+        // Every due packet in one read
+        let mut buffer = vec![0; 256];
+        let len = replay.read(&mut buffer).unwrap();
+        assert_eq!(len, 63);
+        let mut framer = Framer::new();
+        let f = decode(&mut framer, &buffer[..len]);
+        let stamps: Vec<u32> = f.iter().map(|f| f.basic_sensor.time_stamp).collect();
+        assert_eq!(stamps, vec![0x10, 0x24, 0x38]);
+        assert!(f.iter().all(|f| f.basic_sensor.valid));
+        assert_eq!(framer.stats().frames_ok, 3);
+
+        // Finished
+        assert_eq!(replay.read(&mut buffer).unwrap(), 0);
+    }
+
+    #[test]
+    fn single_step_with_small_buffer() {
+        let mut replay = open(ReplayMode::SingleStep, 1.0);
+        let mut buffer = vec![0; 16];
+        assert_eq!(replay.read(&mut buffer).unwrap(), 0);
+
+        // A packet is played even if it takes a few reads
+        replay.control(&ReplayControl::Step).unwrap();
+        assert_eq!(replay.read(&mut buffer).unwrap(), 16);
+        assert_eq!(replay.read(&mut buffer).unwrap(), 5);
+        assert_eq!(replay.read(&mut buffer).unwrap(), 0);
+        assert_eq!(replay.position_us(), 0);
+
+        replay.control(&ReplayControl::Step).unwrap();
+        assert_eq!(replay.read(&mut buffer).unwrap(), 16);
+        assert_eq!(replay.read(&mut buffer).unwrap(), 5);
+        assert_eq!(replay.position_us(), 21_000);
+    }
+
+    #[test]
+    fn seek_past_the_end() {
+        let mut replay = open(ReplayMode::RealTime, 1.0);
+        replay.control(&ReplayControl::Seek(u64::MAX)).unwrap();
+        assert_eq!(replay.position_us(), u64::MAX);
+        let mut buffer = vec![0; 256];
+        assert_eq!(replay.read(&mut buffer).unwrap(), 0);
+    }
+}
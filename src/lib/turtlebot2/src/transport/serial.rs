@@ -0,0 +1,107 @@
+use std::{
+    io::{ErrorKind, Read, Write},
+    time::Duration,
+};
+
+use anyhow::{anyhow, Result};
+use serialport::{SerialPort, SerialPortType};
+
+use crate::api::{ConnectionConfig, FlowControl};
+use crate::transport::Transport;
+
+// SerialTransport talks to a Kobuki over a USB-Serial (FTDI) device
+pub struct SerialTransport {
+    port_name: String,
+    config: ConnectionConfig,
+    // Remembered on the first open to find the device again
+    serial_number: Option<String>,
+    port: Option<Box<dyn SerialPort>>,
+}
+
+impl SerialTransport {
+    pub fn new(port_name: &str, config: &ConnectionConfig) -> SerialTransport {
+        SerialTransport {
+            port_name: port_name.to_string(),
+            config: config.clone(),
+            serial_number: None,
+            port: None,
+        }
+    }
+}
+
+impl Transport for SerialTransport {
+    fn name(&self) -> String {
+        self.port_name.clone()
+    }
+
+    fn open(&mut self) -> Result<()> {
+        let flow_control = match self.config.flow_control {
+            FlowControl::None => serialport::FlowControl::None,
+            FlowControl::Software => serialport::FlowControl::Software,
+            FlowControl::Hardware => serialport::FlowControl::Hardware,
+        };
+        let port = serialport::new(self.port_name.clone(), self.config.baud_rate)
+            .timeout(Duration::from_millis(self.config.read_timeout_ms as u64))
+            .flow_control(flow_control)
+            .open()?;
+        self.port = Some(port);
+        if self.serial_number.is_none() {
+            self.serial_number = serial_number_of(&self.port_name);
+        }
+        Ok(())
+    }
+
+    fn read(&mut self, buffer: &mut [u8]) -> Result<usize> {
+        let port = self
+            .port
+            .as_mut()
+            .ok_or_else(|| anyhow!("Port not opened"))?;
+        match port.read(buffer) {
+            Ok(l) => Ok(l),
+            // Nothing arrived yet, which is not an error for the reader
+            Err(e) if e.kind() == ErrorKind::TimedOut => Ok(0),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn write(&mut self, payload: &[u8]) -> Result<()> {
+        let port = self
+            .port
+            .as_mut()
+            .ok_or_else(|| anyhow!("Port not opened"))?;
+        port.write_all(payload)?;
+        port.flush()?;
+        Ok(())
+    }
+
+    fn close(&mut self) {
+        // Dropping the port closes it
+        self.port = None;
+    }
+
+    fn relocate(&mut self) -> Result<()> {
+        let serial_number = match &self.serial_number {
+            Some(s) => s.clone(),
+            // Nothing to compare, so just try the same name again
+            None => return Ok(()),
+        };
+        let ports = serialport::available_ports()?;
+        for p in ports.iter() {
+            if let SerialPortType::UsbPort(info) = &p.port_type {
+                if info.serial_number.as_deref() == Some(serial_number.as_str()) {
+                    self.port_name = p.port_name.clone();
+                    return Ok(());
+                }
+            }
+        }
+        Err(anyhow!("Device not found: {}", serial_number))
+    }
+}
+
+fn serial_number_of(port_name: &str) -> Option<String> {
+    let ports = serialport::available_ports().ok()?;
+    ports.into_iter().find_map(|p| match p.port_type {
+        SerialPortType::UsbPort(info) if p.port_name == port_name => info.serial_number,
+        _ => None,
+    })
+}
@@ -0,0 +1,163 @@
+use std::{
+    io::{ErrorKind, Read, Write},
+    net::{Shutdown, TcpStream, ToSocketAddrs},
+    time::Duration,
+};
+
+use anyhow::{anyhow, Result};
+
+use crate::api::ConnectionConfig;
+use crate::transport::Transport;
+
+// Prefix of addresses that should go to TcpTransport
+pub const TCP_PREFIX: &str = "tcp://";
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(3);
+
+// TcpTransport talks to a Kobuki behind a raw TCP bridge (ser2net and alike).
+// The bridge forwards the bytes as they are, so the protocol is the same as the serial one.
+pub struct TcpTransport {
+    address: String,
+    read_timeout: Duration,
+    stream: Option<TcpStream>,
+}
+
+impl TcpTransport {
+    // The address should look like tcp://host:port
+    // The baud rate and flow control belong to the bridge, so they are ignored here
+    pub fn new(address: &str, config: &ConnectionConfig) -> TcpTransport {
+        TcpTransport {
+            address: address.to_string(),
+            // A socket can't take 0 as a timeout
+            read_timeout: Duration::from_millis(config.read_timeout_ms.max(1) as u64),
+            stream: None,
+        }
+    }
+
+    fn host_port(&self) -> &str {
+        self.address
+            .strip_prefix(TCP_PREFIX)
+            .unwrap_or(&self.address)
+    }
+}
+
+impl Transport for TcpTransport {
+    fn name(&self) -> String {
+        self.address.clone()
+    }
+
+    fn open(&mut self) -> Result<()> {
+        let addr = self
+            .host_port()
+            .to_socket_addrs()?
+            .next()
+            .ok_or_else(|| anyhow!("What address? {}", self.address))?;
+        let stream = TcpStream::connect_timeout(&addr, CONNECT_TIMEOUT)?;
+        stream.set_read_timeout(Some(self.read_timeout))?;
+        // Commands are tiny, so don't wait for Nagle
+        stream.set_nodelay(true)?;
+        self.stream = Some(stream);
+        Ok(())
+    }
+
+    fn read(&mut self, buffer: &mut [u8]) -> Result<usize> {
+        let stream = self
+            .stream
+            .as_mut()
+            .ok_or_else(|| anyhow!("Port not opened"))?;
+        match stream.read(buffer) {
+            // Unlike the serial port, 0 means the bridge closed the connection
+            Ok(0) => Err(anyhow!("Connection closed by {}", self.address)),
+            Ok(l) => Ok(l),
+            Err(e) if e.kind() == ErrorKind::WouldBlock || e.kind() == ErrorKind::TimedOut => Ok(0),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn write(&mut self, payload: &[u8]) -> Result<()> {
+        let stream = self
+            .stream
+            .as_mut()
+            .ok_or_else(|| anyhow!("Port not opened"))?;
+        stream.write_all(payload)?;
+        stream.flush()?;
+        Ok(())
+    }
+
+    fn close(&mut self) {
+        if let Some(stream) = self.stream.take() {
+            // The other side may have closed it already
+            let _ = stream.shutdown(Shutdown::Both);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::TcpListener;
+    use std::time::Instant;
+
+    use super::*;
+
+    // A connected transport and the bridge side of it
+    fn connect() -> (TcpTransport, TcpStream) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = format!("{}{}", TCP_PREFIX, listener.local_addr().unwrap());
+        let config = ConnectionConfig {
+            read_timeout_ms: 20,
+            ..Default::default()
+        };
+        let mut transport = TcpTransport::new(&address, &config);
+        transport.open().unwrap();
+        let (peer, _) = listener.accept().unwrap();
+        (transport, peer)
+    }
+
+    #[test]
+    fn read() {
+        let (mut transport, mut peer) = connect();
+        peer.write_all(&[0xaa, 0x55, 0x01]).unwrap();
+        let mut buffer = [0; 16];
+        let mut read = Vec::new();
+        let start = Instant::now();
+        while read.len() < 3 && start.elapsed() < Duration::from_secs(2) {
+            let len = transport.read(&mut buffer).unwrap();
+            read.extend_from_slice(&buffer[..len]);
+        }
+        assert_eq!(read, vec![0xaa, 0x55, 0x01]);
+    }
+
+    #[test]
+    fn write() {
+        let (mut transport, mut peer) = connect();
+        transport.write(&[0xaa, 0x55, 0x02]).unwrap();
+        let mut buffer = [0; 3];
+        peer.read_exact(&mut buffer).unwrap();
+        assert_eq!(buffer, [0xaa, 0x55, 0x02]);
+    }
+
+    #[test]
+    fn read_timeout() {
+        let (mut transport, _peer) = connect();
+        let mut buffer = [0; 16];
+        assert_eq!(transport.read(&mut buffer).unwrap(), 0);
+    }
+
+    #[test]
+    fn closed_by_peer() {
+        let (mut transport, peer) = connect();
+        drop(peer);
+        let mut buffer = [0; 16];
+        assert!(transport.read(&mut buffer).is_err());
+    }
+
+    #[test]
+    fn connection_refused() {
+        // Nothing listens on the port once the listener is gone
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = format!("{}{}", TCP_PREFIX, listener.local_addr().unwrap());
+        drop(listener);
+        let mut transport = TcpTransport::new(&address, &ConnectionConfig::default());
+        assert!(transport.open().is_err());
+    }
+}
@@ -0,0 +1,279 @@
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, Result};
+
+use crate::api::{TuneNote, TuneProgress};
+use crate::tx::*;
+
+// The Sound command can't play longer than this at once
+const MAX_SOUND_MS: f64 = 255.0;
+const LATE: Duration = Duration::from_millis(20);
+
+// A note (None for a rest) and how long it lasts in beats
+#[derive(Debug, Clone, PartialEq)]
+pub struct TuneStep {
+    pub frequency: Option<f64>,
+    pub beats: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Tune {
+    pub name: String,
+    pub steps: Vec<TuneStep>,
+    pub tempo_bpm: f64,
+}
+
+impl Tune {
+    // A note can be a name (e.g. A4, C#5) or empty/R for a rest
+    pub fn from_notes(notes: &[TuneNote], tempo_bpm: f64) -> Result<Tune> {
+        let mut steps = Vec::new();
+        for n in notes.iter() {
+            if !(n.beats.is_finite() && n.beats >= 0.0) {
+                return Err(anyhow!("What beats? {}", n.beats));
+            }
+            let note = n.note.trim();
+            let frequency = if note.is_empty() || note.eq_ignore_ascii_case("r") {
+                None
+            } else {
+                Some(note_frequency(note)?)
+            };
+            steps.push(TuneStep {
+                frequency,
+                beats: n.beats,
+            });
+        }
+        check_tempo(tempo_bpm)?;
+        Ok(Tune {
+            name: String::new(),
+            steps,
+            tempo_bpm,
+        })
+    }
+}
+
+pub fn check_tempo(tempo_bpm: f64) -> Result<()> {
+    if !(tempo_bpm.is_finite() && tempo_bpm > 0.0) {
+        return Err(anyhow!("What tempo? {}", tempo_bpm));
+    }
+    Ok(())
+}
+
+// TunePlayer schedules the notes of a tune.
+// The owner waits until deadline() and calls next() to get the Sound command to send.
+// A note longer than the Sound command can play is sent again every 255 ms.
+#[derive(Clone)]
+pub struct TunePlayer {
+    name: String,
+    steps: VecDeque<TuneStep>,
+    // To report the progress
+    total_steps: usize,
+    started: Option<Instant>,
+    tempo_bpm: f64,
+    // The note being played and how long it still lasts
+    current: Option<(Option<f64>, f64)>,
+    deadline: Option<Instant>,
+}
+
+impl TunePlayer {
+    pub fn new() -> TunePlayer {
+        TunePlayer {
+            name: String::new(),
+            steps: VecDeque::new(),
+            total_steps: 0,
+            started: None,
+            tempo_bpm: 120.0,
+            current: None,
+            deadline: None,
+        }
+    }
+
+    // Replaces what is playing, the first note is due now
+    pub fn play(&mut self, tune: Tune) {
+        let now = Instant::now();
+        self.name = tune.name;
+        self.total_steps = tune.steps.len();
+        self.steps = tune.steps.into();
+        self.tempo_bpm = tune.tempo_bpm;
+        self.current = None;
+        self.started = Some(now);
+        self.deadline = Some(now);
+    }
+
+    pub fn stop(&mut self) {
+        self.steps.clear();
+        self.started = None;
+        self.current = None;
+        self.deadline = None;
+    }
+
+    // Applied from the next note
+    pub fn set_tempo(&mut self, tempo_bpm: f64) {
+        self.tempo_bpm = tempo_bpm;
+    }
+
+    pub fn is_playing(&self) -> bool {
+        self.deadline.is_some()
+    }
+
+    // When next() should be called
+    pub fn deadline(&self) -> Option<Instant> {
+        self.deadline
+    }
+
+    // Where the tune is (the step being played is counted as played)
+    pub fn progress(&self) -> TuneProgress {
+        let elapsed_ms = match self.started {
+            Some(s) => s.elapsed().as_secs_f64() * 1000.0,
+            None => 0.0,
+        };
+        let current_ms = match self.current {
+            Some((_, ms)) => ms.max(0.0),
+            None => 0.0,
+        };
+        // The part of the note being sent that is still sounding
+        let sounding_ms = match self.deadline {
+            Some(d) => d.saturating_duration_since(Instant::now()).as_secs_f64() * 1000.0,
+            None => 0.0,
+        };
+        let remaining_ms = sounding_ms
+            + current_ms
+            + self
+                .steps
+                .iter()
+                .map(|s| s.beats.max(0.0) * 60_000.0 / self.tempo_bpm)
+                .sum::<f64>();
+        TuneProgress {
+            playing: self.is_playing(),
+            name: self.name.clone(),
+            step: (self.total_steps - self.steps.len()) as u32,
+            steps: self.total_steps as u32,
+            elapsed_ms,
+            duration_ms: elapsed_ms + remaining_ms,
+        }
+    }
+
+    // Returns the Sound command to send now (None for a rest or the end)
+    pub fn next(&mut self) -> Option<KobukiCommand> {
+        let now = Instant::now();
+        let due = self.deadline?;
+
+        let (frequency, remaining) = match self.current.take() {
+            Some((f, ms)) if ms > 0.0 => (f, ms),
+            _ => match self.steps.pop_front() {
+                Some(s) => (s.frequency, s.beats.max(0.0) * 60_000.0 / self.tempo_bpm),
+                // Finished
+                None => {
+                    self.deadline = None;
+                    return None;
+                }
+            },
+        };
+        let ms = remaining.min(MAX_SOUND_MS);
+        self.current = Some((frequency, remaining - ms));
+        // From the last deadline so the tune doesn't drift
+        // (but from now if the thread was too late to catch up)
+        let start = if now > due + LATE { now } else { due };
+        self.deadline = Some(start + Duration::from_secs_f64(ms / 1000.0));
+
+        let frequency = frequency?;
+        // A note that can't be played is a rest
+        KobukiCommand::sound(frequency, ms.round().max(1.0) as u8).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tune(notes: &[(&str, f64)], tempo_bpm: f64) -> Tune {
+        let notes: Vec<TuneNote> = notes
+            .iter()
+            .map(|(note, beats)| TuneNote {
+                note: note.to_string(),
+                beats: *beats,
+            })
+            .collect();
+        Tune::from_notes(&notes, tempo_bpm).unwrap()
+    }
+
+    // The duration of each Sound command (0 for a rest) until the end
+    fn durations(player: &mut TunePlayer) -> Vec<u64> {
+        let mut durations = Vec::new();
+        while let Some(due) = player.deadline() {
+            let command = player.next();
+            let Some(deadline) = player.deadline() else {
+                assert_eq!(command, None);
+                break;
+            };
+            let ms = (deadline - due).as_millis() as u64;
+            match command {
+                Some(KobukiCommand::Sound { duration_ms, .. }) => {
+                    assert_eq!(duration_ms as u64, ms);
+                    durations.push(ms);
+                }
+                None => durations.push(0),
+                c => panic!("What command? {:?}", c),
+            }
+        }
+        durations
+    }
+
+    #[test]
+    fn bad_beats() {
+        for beats in [-1.0, f64::NAN, f64::INFINITY] {
+            let notes = [TuneNote {
+                note: "A4".to_string(),
+                beats,
+            }];
+            assert!(Tune::from_notes(&notes, 120.0).is_err());
+        }
+    }
+
+    #[test]
+    fn long_notes_are_split() {
+        // 1 beat = 1000 ms at 60 bpm
+        let mut player = TunePlayer::new();
+        player.play(tune(&[("A4", 0.6)], 60.0));
+        assert_eq!(durations(&mut player), vec![255, 255, 90]);
+        assert!(!player.is_playing());
+    }
+
+    #[test]
+    fn rests() {
+        // A rest sends nothing but still takes its time
+        let mut player = TunePlayer::new();
+        player.play(tune(&[("R", 0.1), ("C5", 0.1), ("", 0.1)], 60.0));
+        assert_eq!(durations(&mut player), vec![0, 100, 0]);
+    }
+
+    #[test]
+    fn tempo_from_the_next_note() {
+        // 1 beat = 100 ms at 600 bpm
+        let mut player = TunePlayer::new();
+        player.play(tune(&[("A4", 2.0), ("A4", 1.0)], 600.0));
+        let due = player.deadline().unwrap();
+        assert!(player.next().is_some());
+        assert_eq!(player.deadline().unwrap() - due, Duration::from_millis(200));
+        // 1 beat = 200 ms from the second note
+        player.set_tempo(300.0);
+        assert_eq!(durations(&mut player), vec![200]);
+    }
+
+    #[test]
+    fn deadline_ends_with_the_tune() {
+        let mut player = TunePlayer::new();
+        assert_eq!(player.deadline(), None);
+        player.play(tune(&[("A4", 0.1)], 60.0));
+        assert!(player.deadline().is_some());
+        assert!(player.next().is_some());
+        assert!(player.deadline().is_some());
+        // The last note has been sent, the next call ends the tune
+        assert_eq!(player.next(), None);
+        assert_eq!(player.deadline(), None);
+        assert!(!player.is_playing());
+        assert_eq!(player.next(), None);
+    }
+}
@@ -1,9 +1,13 @@
 #![allow(unused)]
 
 use std::{
-    sync::{Arc, Mutex},
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc, Mutex,
+    },
     thread,
-    time::{Duration, SystemTime, UNIX_EPOCH},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 
 use anyhow::{anyhow, Error, Result};
@@ -13,69 +17,305 @@ use once_cell::sync::OnceCell;
 use serialport::{SerialPort, SerialPortType, UsbPortInfo};
 
 use crate::api::*;
+use crate::battery::*;
+use crate::capture::*;
+use crate::encoder::*;
+use crate::latency::*;
 use crate::rx::*;
+use crate::sound_policy::*;
+use crate::transport::*;
+use crate::tune::*;
 use crate::tx::*;
 
 // Keyword to find USB-Serial devices
 const SERIAL: &str = "kobuki";
-// Static channel to interact with turtlebot
-static SEND: OnceCell<Arc<Mutex<crossbeam::Sender<Command>>>> = OnceCell::new();
-// Static vector to store feedbacks from turtlebot
-static RECEIVE: OnceCell<Arc<Mutex<Vec<Feedback>>>> = OnceCell::new();
+// How often the device watcher looks for Kobuki devices
+const DEVICE_WATCH_INTERVAL_MS: u64 = 1000;
 
-// Will be called by the spawning function
-pub fn set_statics_in_turtlebot(sender: crossbeam::Sender<Command>) {
-    // The global static SEND is used to send command to the turtlebot instance
-    let sender_lock = Arc::new(Mutex::new(sender));
-    SEND.set(sender_lock);
-    // The global static RECEIVE is used to receive feedbacks by Flutter
-    let feedback_lock = Arc::new(Mutex::new(Vec::new()));
-    RECEIVE.set(feedback_lock);
+// Session is what Flutter needs to interact with a turtlebot instance.
+// Each instance owns its own command channel and buffers, so several robots can run side by side.
+#[derive(Clone)]
+pub struct Session {
+    // To send commands to the thread in Turtlebot
+    sender: crossbeam::Sender<Command>,
+    // Will be taken by the Turtlebot instance when spawned
+    receiver: crossbeam::Receiver<Command>,
+    spawned: bool,
+    // To store feedbacks from turtlebot
+    feedbacks: Arc<Mutex<Vec<Feedback>>>,
+    // To store attached/detached events of Kobuki devices
+    device_events: Arc<Mutex<Vec<DeviceEvent>>>,
+    // Measured when Flutter takes feedbacks
+    latency: Arc<Mutex<LatencyMeter>>,
+    // Updated by the serial thread
+    decoder_stats: Arc<Mutex<DecoderStats>>,
+    battery: Arc<Mutex<BatteryStatus>>,
+    tune_progress: Arc<Mutex<TuneProgress>>,
+}
+
+// Sessions by handle (a static so Flutter can find a session by its handle)
+static SESSIONS: OnceCell<Mutex<HashMap<u32, Session>>> = OnceCell::new();
+static NEXT_HANDLE: AtomicU32 = AtomicU32::new(1);
+
+fn sessions() -> &'static Mutex<HashMap<u32, Session>> {
+    SESSIONS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn session(handle: u32) -> Result<Session> {
+    let sessions = sessions().lock().unwrap();
+    sessions
+        .get(&handle)
+        .cloned()
+        .ok_or_else(|| anyhow!("What turtlebot? {}", handle))
+}
+
+// Creates a session and returns its handle
+pub fn new_session() -> u32 {
+    let (sender, receiver) = crossbeam::unbounded();
+    let handle = NEXT_HANDLE.fetch_add(1, Ordering::SeqCst);
+    let session = Session {
+        sender,
+        receiver,
+        spawned: false,
+        feedbacks: Arc::new(Mutex::new(Vec::new())),
+        device_events: Arc::new(Mutex::new(Vec::new())),
+        latency: Arc::new(Mutex::new(LatencyMeter::new())),
+        decoder_stats: Arc::new(Mutex::new(DecoderStats::default())),
+        battery: Arc::new(Mutex::new(BatteryStatus::default())),
+        tune_progress: Arc::new(Mutex::new(TuneProgress::default())),
+    };
+    sessions().lock().unwrap().insert(handle, session);
+    handle
+}
+
+// Should be called ONLY once per session
+pub fn spawn_session(handle: u32, sink: StreamSink<String>) -> Result<()> {
+    let session = {
+        let mut sessions = sessions().lock().unwrap();
+        let session = sessions
+            .get_mut(&handle)
+            .ok_or_else(|| anyhow!("What turtlebot? {}", handle))?;
+        if session.spawned {
+            return Err(anyhow!("Already spawned: {}", handle));
+        }
+        session.spawned = true;
+        session.clone()
+    };
+
+    // The sink is passed to the turtlebot instance so it can actively send result to flutter
+    // => then flutter should call receive_from_turtlebot to take the data
+    let mut ttb = Turtlebot::new(&session, sink);
+    ttb.run();
+    Ok(())
+}
+
+// Dropping the sender stops the thread in Turtlebot
+pub fn remove_session(handle: u32) -> Result<()> {
+    sessions()
+        .lock()
+        .unwrap()
+        .remove(&handle)
+        .map(|_| ())
+        .ok_or_else(|| anyhow!("What turtlebot? {}", handle))
 }
 
 // To send commands to the thread in Turtlebot
-pub fn send(cmd: Command) {
-    let tx_lock = SEND.get().unwrap();
-    let tx = tx_lock.lock().unwrap();
-    tx.send(cmd);
+pub fn send(handle: u32, cmd: Command) -> Result<()> {
+    let session = session(handle)?;
+    session.sender.send(cmd)?;
+    Ok(())
 }
 
 // To read stored Feedbacks by Flutter
-pub fn receive() -> Result<Vec<Feedback>> {
-    let fbd_lock = RECEIVE.get().unwrap();
-    let mut fbd = fbd_lock.lock().unwrap();
-    if fbd.len() > 0 {
-        let fbd_ = fbd.clone();
-        fbd.clear();
-        return Ok(fbd_.clone());
+pub fn receive(handle: u32) -> Result<Vec<Feedback>> {
+    let session = session(handle)?;
+    let mut fbd = session.feedbacks.lock().unwrap();
+    if !fbd.is_empty() {
+        let now = Instant::now();
+        let mut latency = session.latency.lock().unwrap();
+        for f in fbd.iter() {
+            latency.record(f, now);
+        }
+        return Ok(fbd.drain(..).collect());
     }
     Err(anyhow!("What feedback?"))
 }
 
+pub fn latency(handle: u32) -> Result<LatencyStats> {
+    let session = session(handle)?;
+    let latency = session.latency.lock().unwrap();
+    Ok(latency.stats())
+}
+
+pub fn decoder_stats(handle: u32) -> Result<DecoderStats> {
+    let session = session(handle)?;
+    let stats = session.decoder_stats.lock().unwrap();
+    Ok(stats.clone())
+}
+
+pub fn battery_status(handle: u32) -> Result<BatteryStatus> {
+    let session = session(handle)?;
+    let battery = session.battery.lock().unwrap();
+    Ok(battery.clone())
+}
+
+pub fn tune_progress(handle: u32) -> Result<TuneProgress> {
+    let session = session(handle)?;
+    let progress = session.tune_progress.lock().unwrap();
+    Ok(progress.clone())
+}
+
+// To read stored DeviceEvents by Flutter
+pub fn receive_devices(handle: u32) -> Result<Vec<DeviceEvent>> {
+    let session = session(handle)?;
+    let mut events = session.device_events.lock().unwrap();
+    if !events.is_empty() {
+        return Ok(events.drain(..).collect());
+    }
+    Err(anyhow!("What device?"))
+}
+
+// Kobuki uses FTDI FT232R
+const FTDI_VID: u16 = 0x0403;
+const FTDI_PID: u16 = 0x6001;
+
+// How likely the given USB device is a Kobuki
+// - High: the Kobuki serial number on the FTDI chip
+// - Medium: the Kobuki serial number (or product name) on another chip
+// - Low: the FTDI chip whose EEPROM has never been flashed for Kobuki
+fn match_kobuki(info: &UsbPortInfo) -> Option<MatchConfidence> {
+    let contains = |s: &Option<String>| {
+        s.as_ref()
+            .map(|s| s.to_lowercase().contains(SERIAL))
+            .unwrap_or(false)
+    };
+    let is_ftdi = info.vid == FTDI_VID && info.pid == FTDI_PID;
+    let has_serial = contains(&info.serial_number);
+    let has_product = contains(&info.product) || contains(&info.manufacturer);
+
+    if is_ftdi && has_serial {
+        Some(MatchConfidence::High)
+    } else if has_serial || has_product {
+        Some(MatchConfidence::Medium)
+    } else if is_ftdi {
+        Some(MatchConfidence::Low)
+    } else {
+        None
+    }
+}
+
+// All ports that can be a Kobuki, the most likely first
+pub fn available_turtlebot_ports() -> Result<Vec<PortDescriptor>> {
+    let ports = serialport::available_ports()?;
+    let mut found = Vec::new();
+    for p in ports.into_iter() {
+        if let SerialPortType::UsbPort(info) = p.port_type {
+            if let Some(confidence) = match_kobuki(&info) {
+                found.push(PortDescriptor {
+                    port_name: p.port_name,
+                    vid: info.vid,
+                    pid: info.pid,
+                    manufacturer: info.manufacturer.unwrap_or_default(),
+                    product: info.product.unwrap_or_default(),
+                    serial_number: info.serial_number.unwrap_or_default(),
+                    confidence,
+                });
+            }
+        }
+    }
+    found.sort_by_key(|p| std::cmp::Reverse(p.confidence));
+    Ok(found)
+}
+
 pub fn available_tutlebots() -> Result<Vec<String>> {
     let ports = serialport::available_ports()?;
-    if ports.len() < 1 {
+    if ports.is_empty() {
         return Err(anyhow!("No port found! (or check dialout group)"));
     }
 
-    let mut found = Vec::new();
+    let found = available_turtlebot_ports()?;
+    Ok(found.into_iter().map(|p| p.port_name).collect())
+}
 
-    // Need to check if there is any port that has serial number with the given string "kobuki"
-    let mut found_kobuki = false;
-    for p in ports.iter() {
-        match p.port_type.clone() {
-            SerialPortType::UsbPort(info) => {
-                if info.serial_number.unwrap().contains(SERIAL) {
-                    // eprintln!("Found port: {:?} - {:?}", p.port_name, p);
-                    found_kobuki = true;
-                    found.push(p.port_name.clone());
-                }
+// The device watcher is shared by all sessions,
+// so USB is enumerated once however many robots are running.
+struct DeviceWatcher {
+    devices: Vec<PortDescriptor>,
+    subscribers: Vec<crossbeam::Sender<Vec<DeviceEvent>>>,
+}
+
+static DEVICE_WATCHER: OnceCell<Mutex<DeviceWatcher>> = OnceCell::new();
+
+// The watcher thread starts with the first subscriber
+fn device_watcher() -> &'static Mutex<DeviceWatcher> {
+    DEVICE_WATCHER.get_or_init(|| {
+        thread::spawn(watch_devices);
+        Mutex::new(DeviceWatcher {
+            devices: Vec::new(),
+            subscribers: Vec::new(),
+        })
+    })
+}
+
+// Attached/detached events for a session
+// The devices attached so far come first (as attached).
+pub fn subscribe_devices() -> crossbeam::Receiver<Vec<DeviceEvent>> {
+    let (tx, rx) = crossbeam::unbounded();
+    let mut watcher = device_watcher().lock().unwrap();
+    let attached: Vec<DeviceEvent> = watcher
+        .devices
+        .iter()
+        .map(|d| DeviceEvent {
+            attached: true,
+            port: d.clone(),
+        })
+        .collect();
+    if !attached.is_empty() {
+        let _ = tx.send(attached);
+    }
+    watcher.subscribers.push(tx);
+    rx
+}
+
+// Compares the attached devices with the last ones and sends the differences to every session
+fn watch_devices() {
+    let ticker = crossbeam::tick(Duration::from_millis(DEVICE_WATCH_INTERVAL_MS));
+    loop {
+        // Try again next time rather than reporting everything as detached
+        if let Ok(found) = available_turtlebot_ports() {
+            let mut watcher = device_watcher().lock().unwrap();
+            let events = device_changes(&watcher.devices, &found);
+            watcher.devices = found;
+            if !events.is_empty() {
+                // The sessions that are gone are dropped
+                watcher
+                    .subscribers
+                    .retain(|s| s.send(events.clone()).is_ok());
             }
-            _ => (),
-        };
+        }
+        let _ = ticker.recv();
     }
+}
 
-    Ok(found)
+fn device_changes(known: &[PortDescriptor], found: &[PortDescriptor]) -> Vec<DeviceEvent> {
+    let mut events = Vec::new();
+    for d in found.iter() {
+        if !known.iter().any(|k| k.port_name == d.port_name) {
+            events.push(DeviceEvent {
+                attached: true,
+                port: d.clone(),
+            });
+        }
+    }
+    for k in known.iter() {
+        if !found.iter().any(|d| d.port_name == k.port_name) {
+            events.push(DeviceEvent {
+                attached: false,
+                port: k.clone(),
+            });
+        }
+    }
+    events
 }
 
 // TurtlebotData runs the serial thread and passes commands/feedbacks
@@ -83,9 +323,17 @@ pub fn available_tutlebots() -> Result<Vec<String>> {
 pub struct TurtlebotData {
     receiver: crossbeam::Receiver<Command>,
     sink: StreamSink<String>,
-    feedbacks: Vec<Feedback>,
+    feedbacks: Arc<Mutex<Vec<Feedback>>>,
+    device_events: Arc<Mutex<Vec<DeviceEvent>>>,
+    latency: Arc<Mutex<LatencyMeter>>,
+    decoder_stats: Arc<Mutex<DecoderStats>>,
+    battery: Arc<Mutex<BatteryStatus>>,
+    tune_progress: Arc<Mutex<TuneProgress>>,
     current_port_opened: bool,
     current_port_name: String,
+    reconnect_policy: ReconnectPolicy,
+    event_sound_policy: EventSoundPolicy,
+    tune: TunePlayer,
     ttb_tx: crossbeam::Sender<Command>,
     ttb_rx: crossbeam::Receiver<Command>,
     serial_tx: crossbeam::Sender<Command>,
@@ -93,17 +341,25 @@ pub struct TurtlebotData {
 }
 
 impl TurtlebotData {
-    pub fn new(rx: crossbeam::Receiver<Command>, sk: StreamSink<String>) -> TurtlebotData {
+    pub fn new(session: &Session, sk: StreamSink<String>) -> TurtlebotData {
         let (tx1, rx1) = crossbeam::unbounded();
         let (tx2, rx2) = crossbeam::unbounded();
         TurtlebotData {
             // To interact with outside
-            receiver: rx,
+            receiver: session.receiver.clone(),
             sink: sk,
-            feedbacks: Vec::new(),
+            feedbacks: session.feedbacks.clone(),
+            device_events: session.device_events.clone(),
+            latency: session.latency.clone(),
+            decoder_stats: session.decoder_stats.clone(),
+            battery: session.battery.clone(),
+            tune_progress: session.tune_progress.clone(),
             // Serial port state indicators
             current_port_opened: false,
             current_port_name: "".to_string(),
+            reconnect_policy: ReconnectPolicy::default(),
+            event_sound_policy: EventSoundPolicy::default(),
+            tune: TunePlayer::new(),
             // Be careful! - these channels are twisted for bidirectional comm.
             // ttb_tx => serial_rx
             // serial_tx => ttb_rx
@@ -114,111 +370,476 @@ impl TurtlebotData {
         }
     }
 
-    pub fn serial_runner(
+    // Stores the events of the device watcher and notifies Flutter
+    pub fn report_devices(&mut self, events: Vec<DeviceEvent>) {
+        self.device_events
+            .lock()
+            .unwrap()
+            .extend(events.iter().cloned());
+
+        // Flutter will receive and read the vector
+        for e in events.iter() {
+            if e.attached {
+                self.sink.add("attached".to_string());
+            } else {
+                self.sink.add("detached".to_string());
+                // The port in use is gone
+                if self.current_port_opened && e.port.port_name == self.current_port_name {
+                    self.sink.add("port_detached".to_string());
+                }
+            }
+        }
+    }
+
+    pub fn play_tune(&mut self, tune: Tune) {
+        self.tune.play(tune);
+        *self.tune_progress.lock().unwrap() = self.tune.progress();
+        self.sink.add("tune_started".to_string());
+    }
+
+    // Cancels the tune being played (if any)
+    pub fn stop_tune(&mut self) {
+        if !self.tune.is_playing() {
+            return;
+        }
+        let mut progress = self.tune.progress();
+        progress.playing = false;
+        self.tune.stop();
+        *self.tune_progress.lock().unwrap() = progress;
+        self.sink.add("tune_stopped".to_string());
+    }
+
+    // Sends the next note of the tune (called at the deadline of the tune player)
+    // Flutter gets "tune_progress" when a note starts and can read it by tune_progress_command.
+    pub fn play_tune_step(&mut self) {
+        if !self.current_port_opened {
+            self.stop_tune();
+            self.sink.add("port_not_open".to_string());
+            return;
+        }
+        let step = self.tune.progress().step;
+        if let Some(c) = self.tune.next() {
+            self.ttb_tx.send(Command {
+                ty: c.id(),
+                payload: c.encode(),
+                ..Command::new()
+            });
+        }
+        let progress = self.tune.progress();
+        let started_note = progress.step != step;
+        let playing = progress.playing;
+        *self.tune_progress.lock().unwrap() = progress;
+        if !playing {
+            self.sink.add("tune_finished".to_string());
+        } else if started_note {
+            self.sink.add("tune_progress".to_string());
+        }
+    }
+
+    pub fn serial_runner<T: Transport + 'static>(
         &mut self,
-        cmd: Command,
+        mut transport: T,
+        config: ConnectionConfig,
         tx: crossbeam::Sender<Command>,
         rx: crossbeam::Receiver<Command>,
     ) {
+        let policy = self.reconnect_policy.clone();
+        let mut event_sounds = EventSounds::new(self.event_sound_policy.clone());
+        let feedbacks = self.feedbacks.clone();
+        let decoder_stats = self.decoder_stats.clone();
+        let battery = self.battery.clone();
         thread::spawn(move || {
-            // Ticker to periodically read a port if opened
-            let ticker = crossbeam::tick(Duration::from_millis(64));
-            let serial_port_name = cmd.serial_port_name.clone();
-            let port_b = serialport::new(serial_port_name.clone(), 115_200).open();
+            // Ticker to periodically read a port if opened (only for ReadStrategy::Poll)
+            let ticker = crossbeam::tick(Duration::from_millis(config.poll_interval_ms as u64));
+            let mut serial_port_name = transport.name();
+            let port_b = transport.open();
             match port_b {
-                Ok(mut p) => {
+                Ok(_) => {
                     // Need to send back to indicate the port is opened
-                    tx.send(Command {
-                        ty: CommandId::SerialControl,
-                        serial_command: "opened".to_string(),
-                        serial_port_name: serial_port_name.clone(),
-                        payload: Vec::new(),
-                    });
+                    tx.send(serial_event("opened", &serial_port_name));
 
-                    let mut buffer = [0; 4096];
-                    let mut residue = Vec::new();
+                    let mut buffer = vec![0; config.buffer_size.max(1) as usize];
+                    let mut framer = Framer::new();
+                    *decoder_stats.lock().unwrap() = framer.stats().clone();
+                    let mut battery_model = BatteryModel::new();
+                    let mut encoder_tracker = EncoderTracker::new();
+                    // Raw bytes recording, toggled by Flutter
+                    let mut capture: Option<Capture> = None;
+                    if let Some(seq) = event_sounds.connected() {
+                        write_sound_sequence(
+                            &mut transport,
+                            &mut capture,
+                            &tx,
+                            &serial_port_name,
+                            seq,
+                        );
+                    }
 
                     loop {
-                        crossbeam::select! {
-                            recv(rx) -> cmd => {
-                                let c = cmd.unwrap();
-                                if c.serial_command == "close" {
-                                    // Need to send back to indicate the port is closed
-                                    tx.send(Command {
-                                        ty: CommandId::SerialControl,
-                                        serial_command: "closed".to_string(),
-                                        serial_port_name: serial_port_name.clone(),
-                                        payload: Vec::new(),
-                                    });
-                                    // Exit from the loop so the port will be dropped
-                                    break;
-                                }
+                        let cmd = match config.read_strategy {
+                            // Wait for either a command or the next tick to read
+                            ReadStrategy::Poll => crossbeam::select! {
+                                recv(rx) -> cmd => cmd.map(Some).map_err(|_| ()),
+                                recv(ticker) -> _ => Ok(None),
+                            },
+                            // Serve the pending commands first,
+                            // then the read itself waits up to the read timeout
+                            ReadStrategy::Blocking => match rx.try_recv() {
+                                Ok(c) => Ok(Some(c)),
+                                Err(crossbeam::TryRecvError::Empty) => Ok(None),
+                                Err(crossbeam::TryRecvError::Disconnected) => Err(()),
+                            },
+                        };
+                        let cmd = match cmd {
+                            Ok(c) => c,
+                            // The Turtlebot instance is gone
+                            Err(_) => {
+                                transport.close();
+                                break;
                             }
-                            recv(ticker)-> _ => {
-                                let r = p.read(&mut buffer);
-                                let mut len = 0;
-                                match r {
-                                    Ok(l) => {len = l;}
-                                    Err(e) => {
-                                        eprintln!("read failed => exit");
-                                        // Need to send back to indicate there is an issue
-                                        tx.send(Command {
-                                            ty: CommandId::SerialControl,
-                                            serial_command: "error".to_string(),
-                                            serial_port_name: "".to_string(),
-                                            payload: Vec::new(),
-                                        });
-                                        break
+                        };
+
+                        if let Some(c) = cmd {
+                            if c.ty == CommandId::SerialControl {
+                                match c.serial_command.as_str() {
+                                    "close" => {
+                                        if let Some(cap) = capture.take() {
+                                            if let Err(e) = cap.stop() {
+                                                eprintln!("capture failed: {:?}", e);
+                                            }
+                                        }
+                                        // Need to send back to indicate the port is closed
+                                        tx.send(serial_event("closed", &serial_port_name));
+                                        // Exit from the loop so the port will be dropped
+                                        transport.close();
+                                        break;
+                                    }
+                                    "start_capture" => {
+                                        // Only one capture at a time
+                                        if let Some(cap) = capture.take() {
+                                            if let Err(e) = cap.stop() {
+                                                eprintln!("capture failed: {:?}", e);
+                                            }
+                                        }
+                                        match Capture::start(&c.capture_path, &serial_port_name) {
+                                            Ok(cap) => {
+                                                capture = Some(cap);
+                                                tx.send(serial_event(
+                                                    "capture_started",
+                                                    &serial_port_name,
+                                                ));
+                                            }
+                                            Err(e) => {
+                                                eprintln!("capture failed: {:?}", e);
+                                                tx.send(serial_event(
+                                                    "capture_failed",
+                                                    &serial_port_name,
+                                                ));
+                                            }
+                                        }
+                                    }
+                                    "stop_capture" => {
+                                        if let Some(cap) = capture.take() {
+                                            // The end of the file may be lost
+                                            let event = match cap.stop() {
+                                                Ok(_) => "capture_stopped",
+                                                Err(e) => {
+                                                    eprintln!("capture failed: {:?}", e);
+                                                    "capture_failed"
+                                                }
+                                            };
+                                            tx.send(serial_event(event, &serial_port_name));
+                                        }
+                                    }
+                                    "event_sound_policy" => {
+                                        event_sounds.set_policy(c.event_sound_policy.clone());
+                                    }
+                                    "replay" => {
+                                        if let Some(control) = c.replay_control.as_ref() {
+                                            if let Err(e) = transport.control_replay(control) {
+                                                eprintln!("replay control failed: {:?}", e);
+                                                tx.send(serial_event(
+                                                    "replay_failed",
+                                                    &serial_port_name,
+                                                ));
+                                            }
+                                        }
                                     }
+                                    _ => {}
                                 }
-                                let d = decode(&buffer[..len], &residue);
-                                match d {
-                                    // Incoming packets are well decoded.
-                                    // Push to the static vector
-                                    Ok(v) => {
-                                        let(mut f, r) = v;
-                                        let fdb_lock = RECEIVE.get().unwrap();
-                                        let mut fdb = fdb_lock.lock().unwrap();
-                                        fdb.append(&mut f);
-                                        let fdb_locak = Arc::new(Mutex::new(fdb));
-                                        RECEIVE.set(fdb_lock.clone());
-                                        residue = r;
-
-                                        // Need to send back to indicate the feedbacks are ready
-                                        // Then Flutter will receive and read the vector
-                                        tx.send(Command {
-                                            ty: CommandId::SerialControl,
-                                            serial_command: "ready".to_string(),
-                                            serial_port_name: "".to_string(),
-                                            payload: Vec::new(),
+                                continue;
+                            }
+
+                            // All non-SerialControl commands carry a complete packet (with CRC)
+                            // Need to send back the result of each write
+                            let result = transport.write(&c.payload);
+                            record_write(&mut capture, &tx, &serial_port_name, &c.payload, &result);
+                            let serial_command = match result {
+                                Ok(_) => "written".to_string(),
+                                Err(e) => {
+                                    eprintln!("write failed: {:?}", e);
+                                    "write_failed".to_string()
+                                }
+                            };
+                            tx.send(Command {
+                                ty: c.ty,
+                                serial_command,
+                                serial_port_name: serial_port_name.clone(),
+                                ..Command::new()
+                            });
+                            continue;
+                        }
+
+                        let r = transport.read(&mut buffer);
+                        let mut len = 0;
+                        match r {
+                            Ok(l) => {
+                                len = l;
+                            }
+                            Err(e) => {
+                                eprintln!("read failed: {:?}", e);
+                                record(&mut capture, &tx, &serial_port_name, |cap| {
+                                    cap.note("read_failed")
+                                });
+                                let r = if policy.enabled {
+                                    reconnect(&mut transport, &policy, &tx, &rx)
+                                } else {
+                                    Reconnect::GaveUp
+                                };
+                                match r {
+                                    // Start over with the new connection
+                                    Reconnect::Reconnected => {
+                                        serial_port_name = transport.name();
+                                        framer.reset();
+                                        *decoder_stats.lock().unwrap() = framer.stats().clone();
+                                        battery_model = BatteryModel::new();
+                                        // The counters of the robot can start over
+                                        encoder_tracker = EncoderTracker::new();
+                                        record(&mut capture, &tx, &serial_port_name, |cap| {
+                                            cap.note("reconnected")
                                         });
+                                        event_sounds.reset();
+                                        if let Some(seq) = event_sounds.connected() {
+                                            write_sound_sequence(
+                                                &mut transport,
+                                                &mut capture,
+                                                &tx,
+                                                &serial_port_name,
+                                                seq,
+                                            );
+                                        }
+                                        continue;
                                     }
-                                    // If failed to decode? TBD
-                                    Err(e) => {
+                                    // Closed by Flutter while reconnecting
+                                    Reconnect::Closed => break,
+                                    Reconnect::GaveUp => {
+                                        eprintln!("read failed => exit");
+                                        // Need to send back to indicate there is an issue
+                                        tx.send(serial_event("error", ""));
+                                        break;
                                     }
                                 }
                             }
                         }
+                        // Nothing arrived within the read timeout
+                        if len == 0 {
+                            continue;
+                        }
+                        record(&mut capture, &tx, &serial_port_name, |cap| {
+                            cap.rx(&buffer[..len])
+                        });
+                        let mut f = decode(&mut framer, &buffer[..len]);
+                        // Errors are counted rather than reported one by one
+                        *decoder_stats.lock().unwrap() = framer.stats().clone();
+                        if f.is_empty() {
+                            continue;
+                        }
+                        let now = Instant::now();
+                        for f in f.iter_mut().filter(|f| f.basic_sensor.valid) {
+                            f.battery = battery_model.update(&f.basic_sensor, now);
+                            f.encoders = encoder_tracker.update(&f.basic_sensor);
+                            *battery.lock().unwrap() = f.battery.clone();
+                        }
+                        for f in f.iter() {
+                            for seq in event_sounds.update(f) {
+                                write_sound_sequence(
+                                    &mut transport,
+                                    &mut capture,
+                                    &tx,
+                                    &serial_port_name,
+                                    seq,
+                                );
+                            }
+                        }
+                        // Incoming packets are well decoded.
+                        // Push to the static vector
+                        let mut fdb = feedbacks.lock().unwrap();
+                        fdb.append(&mut f);
+
+                        // Need to send back to indicate the feedbacks are ready
+                        // Then Flutter will receive and read the vector
+                        tx.send(serial_event("ready", ""));
                     }
                 }
-                // Failed to open any port.
-                // No state changes.
+                // Failed to open any port (e.g. a TCP connection refused).
+                // No state changes, but Flutter needs to know.
                 Err(e) => {
-                    eprintln!("{:?}", e);
+                    eprintln!("open failed: {:?}", e);
+                    tx.send(serial_event("open_failed", &serial_port_name));
                 }
             }
         });
     }
 }
 
+// Written by the serial thread itself, so Flutter doesn't get "written".
+fn write_sound_sequence<T: Transport>(
+    transport: &mut T,
+    capture: &mut Option<Capture>,
+    tx: &crossbeam::Sender<Command>,
+    serial_port_name: &str,
+    seq: SoundSequence,
+) {
+    let payload = KobukiCommand::SoundSequence(seq).encode();
+    let result = transport.write(&payload);
+    if let Err(e) = &result {
+        eprintln!("event sound failed: {:?}", e);
+    }
+    record_write(capture, tx, serial_port_name, &payload, &result);
+}
+
+// Only the bytes that went out are captured as TX
+fn record_write(
+    capture: &mut Option<Capture>,
+    tx: &crossbeam::Sender<Command>,
+    serial_port_name: &str,
+    payload: &[u8],
+    result: &Result<()>,
+) {
+    record(capture, tx, serial_port_name, |cap| match result {
+        Ok(_) => cap.tx(payload),
+        Err(_) => cap.note("write_failed"),
+    });
+}
+
+// Writes to the capture if any.
+// On the first error (e.g. a full disk) the capture is dropped and Flutter is told.
+fn record<F>(
+    capture: &mut Option<Capture>,
+    tx: &crossbeam::Sender<Command>,
+    serial_port_name: &str,
+    write: F,
+) where
+    F: FnOnce(&mut Capture) -> Result<()>,
+{
+    if let Some(cap) = capture.as_mut() {
+        if let Err(e) = write(cap) {
+            eprintln!("capture failed: {:?}", e);
+            *capture = None;
+            tx.send(serial_event("capture_failed", serial_port_name));
+        }
+    }
+}
+
+// To build a SerialControl command that the serial thread sends back
+fn serial_event(serial_command: &str, serial_port_name: &str) -> Command {
+    Command {
+        ty: CommandId::SerialControl,
+        serial_command: serial_command.to_string(),
+        serial_port_name: serial_port_name.to_string(),
+        ..Command::new()
+    }
+}
+
+enum Reconnect {
+    Reconnected,
+    Closed,
+    GaveUp,
+}
+
+// Tries to open the transport again by the given policy.
+// Commands are still served while waiting so Flutter can close the port or get write_failed.
+fn reconnect<T: Transport>(
+    transport: &mut T,
+    policy: &ReconnectPolicy,
+    tx: &crossbeam::Sender<Command>,
+    rx: &crossbeam::Receiver<Command>,
+) -> Reconnect {
+    transport.close();
+    tx.send(serial_event("reconnecting", &transport.name()));
+
+    let max_interval = Duration::from_millis(policy.max_retry_interval_ms as u64);
+    let mut interval = Duration::from_millis(policy.retry_interval_ms as u64);
+    let mut attempt = 0;
+    loop {
+        // 0 means no limit
+        if policy.max_attempts != 0 && attempt >= policy.max_attempts {
+            return Reconnect::GaveUp;
+        }
+        attempt += 1;
+
+        let timeout = crossbeam::after(interval);
+        loop {
+            crossbeam::select! {
+                recv(rx) -> cmd => {
+                    let c = match cmd {
+                        Ok(c) => c,
+                        // The Turtlebot instance is gone
+                        Err(_) => return Reconnect::Closed,
+                    };
+                    if c.ty == CommandId::SerialControl {
+                        if c.serial_command == "close" {
+                            tx.send(serial_event("closed", &transport.name()));
+                            return Reconnect::Closed;
+                        }
+                        continue;
+                    }
+                    tx.send(Command {
+                        ty: c.ty,
+                        serial_command: "write_failed".to_string(),
+                        serial_port_name: transport.name(),
+                        ..Command::new()
+                    });
+                }
+                recv(timeout) -> _ => break,
+            }
+        }
+
+        // The device can be enumerated with another name after re-plugging
+        if policy.relocate_by_serial_number {
+            if let Err(e) = transport.relocate() {
+                eprintln!("relocate failed: {:?}", e);
+            }
+        }
+        match transport.open() {
+            Ok(_) => {
+                tx.send(serial_event("reconnected", &transport.name()));
+                return Reconnect::Reconnected;
+            }
+            Err(e) => {
+                eprintln!("reconnect attempt {} failed: {:?}", attempt, e);
+            }
+        }
+
+        interval = next_interval(interval, policy.backoff_multiplier, max_interval);
+    }
+}
+
+// Exponential backoff, saturating at max_interval
+fn next_interval(interval: Duration, multiplier: f64, max_interval: Duration) -> Duration {
+    Duration::try_from_secs_f64(interval.as_secs_f64() * multiplier.max(1.0))
+        .unwrap_or(max_interval)
+        .min(max_interval)
+}
+
 // Turtlebot handles the communication between Flutter and serial device
 pub struct Turtlebot {
     turtlebot_lock: Arc<Mutex<TurtlebotData>>,
 }
 
 impl Turtlebot {
-    pub fn new(rx: crossbeam::Receiver<Command>, sk: StreamSink<String>) -> Turtlebot {
-        let ttb_data = TurtlebotData::new(rx, sk);
+    pub fn new(session: &Session, sk: StreamSink<String>) -> Turtlebot {
+        let ttb_data = TurtlebotData::new(session, sk);
         Turtlebot {
             turtlebot_lock: Arc::new(Mutex::new(ttb_data)),
         }
@@ -231,25 +852,78 @@ impl Turtlebot {
         thread::spawn(move || {
             // Unlock the mutex
             let mut ttb_data = ttb_lock.lock().unwrap();
+            // Attached/detached events from the shared device watcher
+            let device_rx = subscribe_devices();
             // Enter the loop
             loop {
+                // Fires when the next note of the tune is due
+                let tune_timer = match ttb_data.tune.deadline() {
+                    Some(d) => crossbeam::at(d),
+                    None => crossbeam::never(),
+                };
                 crossbeam::select! {
                     // From Flutter => the serial thread
                     recv(ttb_data.receiver) -> cmd =>{
-                        let cmd = cmd.unwrap();
+                        let cmd = match cmd {
+                            Ok(c) => c,
+                            // The session is removed, so close the port and exit
+                            Err(_) => {
+                                if ttb_data.current_port_opened {
+                                    let mut c = Command::new();
+                                    c.serial_command = "close".to_string();
+                                    ttb_data.ttb_tx.send(c);
+                                }
+                                break;
+                            }
+                        };
                         eprintln!("received");
                         match cmd.ty {
                             CommandId::SerialControl => {
                                 if cmd.serial_command == "open" && !ttb_data.current_port_opened {
-                                    let cmd = cmd.clone();
+                                    let config = cmd.connection_config.clone();
+                                    let transport = new_transport(&cmd.serial_port_name, &config);
                                     let tx = ttb_data.serial_tx.clone();
                                     let rx = ttb_data.serial_rx.clone();
-                                    ttb_data.serial_runner(cmd, tx, rx);
+                                    ttb_data.serial_runner(transport, config, tx, rx);
+                                } else if cmd.serial_command == "close" && ttb_data.current_port_opened {
+                                    ttb_data.ttb_tx.send(cmd);
+                                } else if cmd.serial_command == "event_sound_policy" {
+                                    // For the next open and the open port
+                                    ttb_data.event_sound_policy = cmd.event_sound_policy.clone();
+                                    if ttb_data.current_port_opened {
+                                        ttb_data.ttb_tx.send(cmd);
+                                    }
+                                } else if cmd.serial_command == "reconnect_policy" {
+                                    // Will be used from the next open
+                                    ttb_data.reconnect_policy = cmd.reconnect_policy;
+                                } else if cmd.serial_command == "play_tune" {
+                                    // The notes are sent by this thread on schedule
+                                    if let (true, Some(tune)) = (ttb_data.current_port_opened, cmd.tune) {
+                                        ttb_data.play_tune(tune);
+                                    } else {
+                                        ttb_data.sink.add("port_not_open".to_string());
+                                    }
+                                } else if cmd.serial_command == "tune_tempo" {
+                                    ttb_data.tune.set_tempo(cmd.tempo_bpm);
+                                } else if cmd.serial_command == "stop_tune" {
+                                    ttb_data.stop_tune();
+                                } else if ["start_capture", "stop_capture", "replay"].contains(&cmd.serial_command.as_str()) {
+                                    // Only the serial thread has the transport
+                                    if ttb_data.current_port_opened {
+                                        ttb_data.ttb_tx.send(cmd);
+                                    } else {
+                                        ttb_data.sink.add("port_not_open".to_string());
+                                    }
                                 }
                             }
                             // All non-SerialControl commands
+                            // Only the serial thread can write them, so report back if there is no port
                             _ => {
-                                ttb_data.ttb_tx.send(cmd);
+                                if ttb_data.current_port_opened {
+                                    ttb_data.ttb_tx.send(cmd);
+                                } else {
+                                    ttb_data.sink.add("port_not_open".to_string());
+                                }
                             }
                         }
                     }
@@ -260,23 +934,294 @@ impl Turtlebot {
                             "opened" => {
                                 ttb_data.current_port_opened = true;
                                 ttb_data.current_port_name= c.serial_port_name;
+                                ttb_data.latency.lock().unwrap().reset();
+                                ttb_data.sink.add("opened".to_string());
                             }
                             "closed" => {
+                                ttb_data.stop_tune();
                                 ttb_data.current_port_opened = false;
                                 ttb_data.current_port_name= "".to_string();
+                                ttb_data.sink.add("closed".to_string());
+                            }
+                            "open_failed" => {
+                                ttb_data.sink.add("open_failed".to_string());
                             }
                             "error" => {
+                                ttb_data.stop_tune();
                                 ttb_data.current_port_opened = false;
                                 ttb_data.current_port_name = "".to_string();
+                                ttb_data.sink.add("error".to_string());
+                            }
+                            // The port stays "opened" while reconnecting,
+                            // so commands in the meantime will be reported as write_failed
+                            "reconnecting" => {
+                                ttb_data.sink.add("reconnecting".to_string());
+                            }
+                            // The device can come back with another name
+                            "reconnected" => {
+                                ttb_data.current_port_name = c.serial_port_name;
+                                ttb_data.latency.lock().unwrap().reset();
+                                ttb_data.sink.add("reconnected".to_string());
                             }
                             "ready" => {
                                 ttb_data.sink.add("ready".to_string());
                             }
+                            // With the command id so Flutter can tell which command it was
+                            // e.g. "written:BaseControl", "write_failed:Sound"
+                            "written" | "write_failed" => {
+                                ttb_data.sink.add(format!("{}:{:?}", c.serial_command, c.ty));
+                            }
+                            "capture_started" | "capture_stopped" | "capture_failed" | "replay_failed" => {
+                                ttb_data.sink.add(c.serial_command);
+                            }
                             _ => {}
                         }
                     }
+                    // Device watcher
+                    recv(device_rx) -> events => {
+                        if let Ok(events) = events {
+                            ttb_data.report_devices(events);
+                        }
+                    }
+                    // Tune player
+                    recv(tune_timer) -> _ => {
+                        ttb_data.play_tune_step();
+                    }
                 }
             }
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use flutter_rust_bridge::rust2dart::Rust2Dart;
+
+    use super::*;
+
+    const WAIT: Duration = Duration::from_secs(2);
+
+    fn next_event(rx: &crossbeam::Receiver<Command>) -> Command {
+        rx.recv_timeout(WAIT)
+            .expect("No event from the serial thread")
+    }
+
+    #[test]
+    fn serial_runner_round_trip() {
+        let handle = new_session();
+        let session = session(handle).unwrap();
+        // Nothing reaches Flutter without a port, which is fine here
+        let mut data = TurtlebotData::new(&session, StreamSink::new(Rust2Dart::new(0)));
+        let transport = MemoryTransport::new();
+        let config = ConnectionConfig {
+            read_strategy: ReadStrategy::Blocking,
+            ..Default::default()
+        };
+        let (to_serial, serial_rx) = crossbeam::unbounded();
+        let (serial_tx, from_serial) = crossbeam::unbounded();
+        data.serial_runner(transport.clone(), config, serial_tx, serial_rx);
+        assert_eq!(next_event(&from_serial).serial_command, "opened");
+
+        // Flutter => robot
+        let command = KobukiCommand::drive(0.1, 0.0);
+        to_serial
+            .send(Command {
+                ty: command.id(),
+                payload: command.encode(),
+                ..Command::new()
+            })
+            .unwrap();
+        let e = next_event(&from_serial);
+        assert_eq!(e.serial_command, "written");
+        assert_eq!(e.ty, CommandId::BaseControl);
+        assert_eq!(*transport.written.lock().unwrap(), vec![command.encode()]);
+
+        // Robot => Flutter: basic sensor data (encoders 0x1234/0x5678, battery 16.5 V)
+        let basic_sensor = [
+            0x01, 0x0f, 0x10, 0x00, 0x01, 0x00, 0x00, 0x34, 0x12, 0x78, 0x56, 0x00, 0x00, 0x00,
+            0x00, 0xa5, 0x00,
+        ];
+        transport.push(&packet(&basic_sensor));
+        assert_eq!(next_event(&from_serial).serial_command, "ready");
+        let f = receive(handle).unwrap();
+        assert_eq!(f.len(), 1);
+        assert!(f[0].basic_sensor.valid);
+        assert_eq!(f[0].basic_sensor.time_stamp, 0x10);
+        assert_eq!(f[0].basic_sensor.bumper, 0x01);
+        assert_eq!(f[0].basic_sensor.left_encoder, 0x1234);
+        assert_eq!(f[0].basic_sensor.right_encoder, 0x5678);
+        assert!((f[0].basic_sensor.battery_volts - 16.5).abs() < 1e-9);
+        assert!(f[0].battery.valid);
+        assert_eq!(decoder_stats(handle).unwrap().frames_ok, 1);
+
+        to_serial.send(serial_event("close", "")).unwrap();
+        assert_eq!(next_event(&from_serial).serial_command, "closed");
+        remove_session(handle).unwrap();
+    }
+
+    #[test]
+    fn tcp_peer_close_reconnects() {
+        let handle = new_session();
+        let session = session(handle).unwrap();
+        let mut data = TurtlebotData::new(&session, StreamSink::new(Rust2Dart::new(0)));
+        data.reconnect_policy = ReconnectPolicy {
+            retry_interval_ms: 10,
+            relocate_by_serial_number: false,
+            ..Default::default()
+        };
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = format!("{}{}", TCP_PREFIX, listener.local_addr().unwrap());
+        let config = ConnectionConfig::default();
+        let transport = TcpTransport::new(&address, &config);
+        let (to_serial, serial_rx) = crossbeam::unbounded();
+        let (serial_tx, from_serial) = crossbeam::unbounded();
+        data.serial_runner(transport, config, serial_tx, serial_rx);
+        assert_eq!(next_event(&from_serial).serial_command, "opened");
+
+        // The bridge drops the connection, then takes the next one
+        let (peer, _) = listener.accept().unwrap();
+        drop(peer);
+        assert_eq!(next_event(&from_serial).serial_command, "reconnecting");
+        let (_peer, _) = listener.accept().unwrap();
+        assert_eq!(next_event(&from_serial).serial_command, "reconnected");
+
+        to_serial.send(serial_event("close", "")).unwrap();
+        assert_eq!(next_event(&from_serial).serial_command, "closed");
+        remove_session(handle).unwrap();
+    }
+
+    #[test]
+    fn open_failed() {
+        let handle = new_session();
+        let session = session(handle).unwrap();
+        let mut data = TurtlebotData::new(&session, StreamSink::new(Rust2Dart::new(0)));
+        // Nothing listens on the port once the listener is gone
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = format!("{}{}", TCP_PREFIX, listener.local_addr().unwrap());
+        drop(listener);
+        let config = ConnectionConfig::default();
+        let (_to_serial, serial_rx) = crossbeam::unbounded();
+        let (serial_tx, from_serial) = crossbeam::unbounded();
+        data.serial_runner(
+            TcpTransport::new(&address, &config),
+            config,
+            serial_tx,
+            serial_rx,
+        );
+        let e = next_event(&from_serial);
+        assert_eq!(e.serial_command, "open_failed");
+        assert_eq!(e.serial_port_name, address);
+        remove_session(handle).unwrap();
+    }
+
+    #[test]
+    fn backoff() {
+        let max = Duration::from_millis(8000);
+        let next = |ms, multiplier| next_interval(Duration::from_millis(ms), multiplier, max);
+        assert_eq!(next(500, 2.0), Duration::from_millis(1000));
+        assert_eq!(next(6000, 2.0), max);
+        // Never shrinks
+        assert_eq!(next(500, 0.5), Duration::from_millis(500));
+        // Too big for Duration
+        assert_eq!(next(500, f64::INFINITY), max);
+        assert_eq!(next(500, f64::MAX), max);
+    }
+
+    fn port(name: &str) -> PortDescriptor {
+        PortDescriptor {
+            port_name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn changes(known: &[&str], found: &[&str]) -> Vec<(bool, String)> {
+        let known: Vec<PortDescriptor> = known.iter().map(|n| port(n)).collect();
+        let found: Vec<PortDescriptor> = found.iter().map(|n| port(n)).collect();
+        device_changes(&known, &found)
+            .into_iter()
+            .map(|e| (e.attached, e.port.port_name))
+            .collect()
+    }
+
+    #[test]
+    fn device_changes_diff() {
+        let usb0 = "/dev/ttyUSB0".to_string();
+        let usb1 = "/dev/ttyUSB1".to_string();
+        // Nothing changed
+        assert!(changes(&[], &[]).is_empty());
+        assert!(changes(&["/dev/ttyUSB0"], &["/dev/ttyUSB0"]).is_empty());
+        // Attached, the new ones in the found order
+        assert_eq!(
+            changes(&[], &["/dev/ttyUSB0", "/dev/ttyUSB1"]),
+            vec![(true, usb0.clone()), (true, usb1.clone())]
+        );
+        // Detached
+        assert_eq!(
+            changes(&["/dev/ttyUSB0", "/dev/ttyUSB1"], &["/dev/ttyUSB1"]),
+            vec![(false, usb0.clone())]
+        );
+        // Re-plugged with another name => attached first, then detached
+        assert_eq!(
+            changes(&["/dev/ttyUSB0"], &["/dev/ttyUSB1"]),
+            vec![(true, usb1), (false, usb0)]
+        );
+    }
+
+    #[test]
+    fn match_kobuki_confidence() {
+        let kobuki = || Some("kobuki_A1B2C3".to_string());
+        let other = || Some("Other".to_string());
+        let info = |vid, pid, serial_number, product, manufacturer| UsbPortInfo {
+            vid,
+            pid,
+            serial_number,
+            manufacturer,
+            product,
+        };
+        let cases = [
+            // FTDI with the Kobuki serial number
+            (
+                info(FTDI_VID, FTDI_PID, kobuki(), other(), other()),
+                Some(MatchConfidence::High),
+            ),
+            (
+                info(FTDI_VID, FTDI_PID, kobuki(), None, None),
+                Some(MatchConfidence::High),
+            ),
+            // Another chip with the Kobuki serial number or names
+            (
+                info(0x1234, 0x5678, kobuki(), None, None),
+                Some(MatchConfidence::Medium),
+            ),
+            (
+                info(0x1234, 0x5678, None, Some("Kobuki".to_string()), None),
+                Some(MatchConfidence::Medium),
+            ),
+            (
+                info(0x1234, 0x5678, None, None, Some("KOBUKI".to_string())),
+                Some(MatchConfidence::Medium),
+            ),
+            // FTDI with a Kobuki product name but no serial number
+            (
+                info(FTDI_VID, FTDI_PID, None, Some("Kobuki".to_string()), None),
+                Some(MatchConfidence::Medium),
+            ),
+            // FTDI that has never been flashed
+            (
+                info(FTDI_VID, FTDI_PID, other(), other(), other()),
+                Some(MatchConfidence::Low),
+            ),
+            (
+                info(FTDI_VID, FTDI_PID, None, None, None),
+                Some(MatchConfidence::Low),
+            ),
+            // Same VID, another PID
+            (info(FTDI_VID, 0x6015, None, None, None), None),
+            (info(0x1234, 0x5678, other(), other(), other()), None),
+            (info(0x1234, 0x5678, None, None, None), None),
+        ];
+        for (info, expected) in cases.iter() {
+            assert_eq!(match_kobuki(info), *expected, "{:?}", info);
+        }
+    }
+}
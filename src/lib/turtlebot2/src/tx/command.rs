@@ -0,0 +1,341 @@
+use crate::api::SoundSequence;
+use crate::rx::*;
+
+// The bits of RequestExtra
+const EXTRA_HARDWARE_VERSION: u16 = 0x01;
+const EXTRA_FIRMWARE_VERSION: u16 = 0x02;
+const EXTRA_UNIQUE_DEVICE_ID: u16 = 0x08;
+
+// The distance between the wheels (m)
+const WHEEL_BASE: f64 = 0.23;
+// The limits of the robot
+pub const MAX_LINEAR_VELOCITY: f64 = 0.7; // m/s
+pub const MAX_ANGULAR_VELOCITY: f64 = std::f64::consts::PI; // rad/s
+
+// Under this, a velocity is taken as 0
+const EPSILON: f64 = 0.0001;
+
+// KobukiCommand is a command as the protocol defines it.
+// Every command to the robot should be built by encode() rather than by hand.
+#[derive(Debug, Clone, PartialEq)]
+pub enum KobukiCommand {
+    // mm/s and mm (0: straight, 1: turn in place)
+    BaseControl {
+        speed: i16,
+        radius: i16,
+    },
+    // note = 1 / (frequency * 0.00000275)
+    Sound {
+        note: u16,
+        duration_ms: u8,
+    },
+    SoundSequence(SoundSequence),
+    RequestExtra {
+        hardware_version: bool,
+        firmware_version: bool,
+        unique_device_id: bool,
+    },
+    // Digital outputs (bit 0-3), external powers (bit 4-7) and LEDs (bit 8-11)
+    GeneralPurposeOutput(u16),
+    // The gains are x1000
+    SetControllerGain {
+        user_configured: bool,
+        p: u32,
+        i: u32,
+        d: u32,
+    },
+    GetControllerGain,
+}
+
+impl KobukiCommand {
+    // BaseControl by the linear (m/s, forward +) and angular (rad/s, counterclockwise +) velocity
+    // The same conversion as the Kobuki driver does:
+    // - No rotation => radius 0 (straight)
+    // - No translation (or a tiny radius) => radius 1 (turn in place), speed of a wheel
+    // - Otherwise => speed of the outer wheel along the arc
+    // Both are scaled down together if the outer wheel would go over MAX_LINEAR_VELOCITY,
+    // so the robot keeps the radius but slows down.
+    pub fn drive(linear: f64, angular: f64) -> KobukiCommand {
+        let vx = clamp(linear, MAX_LINEAR_VELOCITY);
+        let wz = clamp(angular, MAX_ANGULAR_VELOCITY);
+        let outer = vx.abs() + wz.abs() * WHEEL_BASE / 2.0;
+        let scale = if outer > MAX_LINEAR_VELOCITY {
+            MAX_LINEAR_VELOCITY / outer
+        } else {
+            1.0
+        };
+        let (vx, wz) = (vx * scale, wz * scale);
+
+        let (speed, radius) = if wz.abs() < EPSILON {
+            (vx * 1000.0, 0.0)
+        } else {
+            let radius = vx * 1000.0 / wz;
+            if vx.abs() < EPSILON || radius.abs() <= 1.0 {
+                (WHEEL_BASE * 1000.0 * wz / 2.0, 1.0)
+            } else if radius > 0.0 {
+                ((radius + WHEEL_BASE * 1000.0 / 2.0) * wz, radius)
+            } else {
+                ((radius - WHEEL_BASE * 1000.0 / 2.0) * wz, radius)
+            }
+        };
+
+        // A big radius is as good as straight, so it saturates
+        KobukiCommand::BaseControl {
+            speed: speed.round().clamp(i16::MIN as f64, i16::MAX as f64) as i16,
+            radius: radius.round().clamp(i16::MIN as f64, i16::MAX as f64) as i16,
+        }
+    }
+
+    pub fn id(&self) -> CommandId {
+        match self {
+            KobukiCommand::BaseControl { .. } => CommandId::BaseControl,
+            KobukiCommand::Sound { .. } => CommandId::Sound,
+            KobukiCommand::SoundSequence(_) => CommandId::SoundSequence,
+            KobukiCommand::RequestExtra { .. } => CommandId::RequestExtra,
+            KobukiCommand::GeneralPurposeOutput(_) => CommandId::GeneralPurposeOutput,
+            KobukiCommand::SetControllerGain { .. } => CommandId::SetControllerGain,
+            KobukiCommand::GetControllerGain => CommandId::GetControllerGain,
+        }
+    }
+
+    // The data of the sub-payload (little endian)
+    fn data(&self) -> Vec<u8> {
+        let mut d = Vec::new();
+        match self {
+            KobukiCommand::BaseControl { speed, radius } => {
+                d.extend(speed.to_le_bytes());
+                d.extend(radius.to_le_bytes());
+            }
+            KobukiCommand::Sound { note, duration_ms } => {
+                d.extend(note.to_le_bytes());
+                d.push(*duration_ms);
+            }
+            KobukiCommand::SoundSequence(seq) => d.push(*seq as u8),
+            KobukiCommand::RequestExtra {
+                hardware_version,
+                firmware_version,
+                unique_device_id,
+            } => {
+                let mut flags = 0;
+                if *hardware_version {
+                    flags |= EXTRA_HARDWARE_VERSION;
+                }
+                if *firmware_version {
+                    flags |= EXTRA_FIRMWARE_VERSION;
+                }
+                if *unique_device_id {
+                    flags |= EXTRA_UNIQUE_DEVICE_ID;
+                }
+                d.extend(flags.to_le_bytes());
+            }
+            KobukiCommand::GeneralPurposeOutput(flags) => d.extend(flags.to_le_bytes()),
+            KobukiCommand::SetControllerGain {
+                user_configured,
+                p,
+                i,
+                d: dd,
+            } => {
+                d.push(*user_configured as u8);
+                d.extend(p.to_le_bytes());
+                d.extend(i.to_le_bytes());
+                d.extend(dd.to_le_bytes());
+            }
+            // Unused
+            KobukiCommand::GetControllerGain => d.push(0),
+        }
+        d
+    }
+
+    // 0xaa | 0x55 | length | id | size | data | checksum
+    // The length covers the sub-payload (id + size + data),
+    // and the checksum is the XOR of the length and the sub-payload.
+    pub fn encode(&self) -> Vec<u8> {
+        let data = self.data();
+        let mut packet = vec![
+            0xaa,
+            0x55,
+            data.len() as u8 + 2,
+            self.id() as u8,
+            data.len() as u8,
+        ];
+        packet.extend(data);
+        let checksum = packet[2..].iter().fold(0, |acc, c| acc ^ c);
+        packet.push(checksum);
+        packet
+    }
+}
+
+// Also 0 for NaN
+fn clamp(v: f64, max: f64) -> f64 {
+    if v.is_nan() {
+        return 0.0;
+    }
+    v.clamp(-max, max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base_control() {
+        let c = KobukiCommand::BaseControl {
+            speed: 100,
+            radius: 0,
+        };
+        assert_eq!(
+            c.encode(),
+            vec![0xaa, 0x55, 0x06, 0x01, 0x04, 0x64, 0x00, 0x00, 0x00, 0x67]
+        );
+        let c = KobukiCommand::BaseControl {
+            speed: -100,
+            radius: 1,
+        };
+        assert_eq!(
+            c.encode(),
+            vec![0xaa, 0x55, 0x06, 0x01, 0x04, 0x9c, 0xff, 0x01, 0x00, 0x61]
+        );
+    }
+
+    #[test]
+    fn sound() {
+        // A4 (440 Hz) for 100 ms
+        let c = KobukiCommand::Sound {
+            note: 826,
+            duration_ms: 100,
+        };
+        assert_eq!(
+            c.encode(),
+            vec![0xaa, 0x55, 0x05, 0x03, 0x03, 0x3a, 0x03, 0x64, 0x58]
+        );
+    }
+
+    #[test]
+    fn sound_sequence() {
+        let c = KobukiCommand::SoundSequence(SoundSequence::Off);
+        assert_eq!(c.encode(), vec![0xaa, 0x55, 0x03, 0x04, 0x01, 0x01, 0x07]);
+        let c = KobukiCommand::SoundSequence(SoundSequence::CleaningEnd);
+        assert_eq!(c.encode(), vec![0xaa, 0x55, 0x03, 0x04, 0x01, 0x06, 0x00]);
+    }
+
+    #[test]
+    fn request_extra() {
+        let c = KobukiCommand::RequestExtra {
+            hardware_version: true,
+            firmware_version: true,
+            unique_device_id: true,
+        };
+        assert_eq!(
+            c.encode(),
+            vec![0xaa, 0x55, 0x04, 0x09, 0x02, 0x0b, 0x00, 0x04]
+        );
+    }
+
+    #[test]
+    fn general_purpose_output() {
+        // All LEDs on
+        let c = KobukiCommand::GeneralPurposeOutput(0x0f00);
+        assert_eq!(
+            c.encode(),
+            vec![0xaa, 0x55, 0x04, 0x0c, 0x02, 0x00, 0x0f, 0x05]
+        );
+    }
+
+    #[test]
+    fn set_controller_gain() {
+        // P 100, I 0.1, D 2 (x1000)
+        let c = KobukiCommand::SetControllerGain {
+            user_configured: true,
+            p: 100_000,
+            i: 100,
+            d: 2_000,
+        };
+        assert_eq!(
+            c.encode(),
+            vec![
+                0xaa, 0x55, 0x0f, 0x0d, 0x0d, 0x01, 0xa0, 0x86, 0x01, 0x00, 0x64, 0x00, 0x00, 0x00,
+                0xd0, 0x07, 0x00, 0x00, 0x9a
+            ]
+        );
+    }
+
+    #[test]
+    fn get_controller_gain() {
+        let c = KobukiCommand::GetControllerGain;
+        assert_eq!(c.encode(), vec![0xaa, 0x55, 0x03, 0x0e, 0x01, 0x00, 0x0c]);
+    }
+
+    fn base(speed: i16, radius: i16) -> KobukiCommand {
+        KobukiCommand::BaseControl { speed, radius }
+    }
+
+    #[test]
+    fn drive_straight() {
+        assert_eq!(KobukiCommand::drive(0.3, 0.0), base(300, 0));
+        assert_eq!(KobukiCommand::drive(-0.3, 0.0), base(-300, 0));
+        // A tiny rotation doesn't count
+        assert_eq!(KobukiCommand::drive(0.3, 0.00005), base(300, 0));
+    }
+
+    #[test]
+    fn drive_rotation() {
+        // Half the wheel base (115 mm) x 1 rad/s
+        assert_eq!(KobukiCommand::drive(0.0, 1.0), base(115, 1));
+        assert_eq!(KobukiCommand::drive(0.0, -1.0), base(-115, 1));
+        // Too small a radius to drive along
+        assert_eq!(KobukiCommand::drive(0.0005, 1.0), base(115, 1));
+    }
+
+    #[test]
+    fn drive_arc() {
+        // 500 mm radius, the outer wheel at (500 + 115) mm x 0.4 rad/s
+        assert_eq!(KobukiCommand::drive(0.2, 0.4), base(246, 500));
+        assert_eq!(KobukiCommand::drive(0.2, -0.4), base(246, -500));
+        assert_eq!(KobukiCommand::drive(-0.2, 0.4), base(-246, -500));
+        assert_eq!(KobukiCommand::drive(-0.2, -0.4), base(-246, 500));
+    }
+
+    #[test]
+    fn drive_clamping() {
+        assert_eq!(KobukiCommand::drive(2.0, 0.0), base(700, 0));
+        assert_eq!(KobukiCommand::drive(-2.0, 0.0), base(-700, 0));
+        // 115 mm x PI rad/s
+        assert_eq!(KobukiCommand::drive(0.0, 10.0), base(361, 1));
+        assert_eq!(KobukiCommand::drive(0.0, -10.0), base(-361, 1));
+        assert_eq!(KobukiCommand::drive(f64::INFINITY, 0.0), base(700, 0));
+    }
+
+    #[test]
+    fn drive_outer_wheel_limit() {
+        // 700 / PI = 223 mm radius, the outer wheel at (223 + 115) mm x PI rad/s = 1061 mm/s
+        // => both scaled down to keep the radius at 700 mm/s
+        assert_eq!(
+            KobukiCommand::drive(0.7, MAX_ANGULAR_VELOCITY),
+            base(700, 223)
+        );
+        assert_eq!(
+            KobukiCommand::drive(0.7, -MAX_ANGULAR_VELOCITY),
+            base(700, -223)
+        );
+        assert_eq!(
+            KobukiCommand::drive(-0.7, MAX_ANGULAR_VELOCITY),
+            base(-700, -223)
+        );
+        // Under the limit => as is
+        assert_eq!(KobukiCommand::drive(0.3, 1.0), base(415, 300));
+    }
+
+    #[test]
+    fn drive_radius_saturation() {
+        // About 4.7 km, as good as straight
+        assert_eq!(KobukiCommand::drive(0.7, 0.00015), base(700, 32767));
+        assert_eq!(KobukiCommand::drive(0.7, -0.00015), base(700, -32768));
+    }
+
+    #[test]
+    fn drive_nan() {
+        assert_eq!(KobukiCommand::drive(f64::NAN, f64::NAN), base(0, 0));
+        assert_eq!(KobukiCommand::drive(f64::NAN, 1.0), base(115, 1));
+        assert_eq!(KobukiCommand::drive(0.3, f64::NAN), base(300, 0));
+    }
+}
@@ -1,5 +1,11 @@
 #![allow(unused)]
 
+mod command;
+mod sound;
+
+pub use command::KobukiCommand;
+pub use sound::*;
+
 // Variant enum
 #[derive(Debug, FromPrimitive, ToPrimitive)]
 pub enum FeedbackId {
@@ -17,7 +23,7 @@ pub enum FeedbackId {
 }
 
 // These can be used to get the size of payload
-// Gyro sensor size can be 14 or 20 bytes
+// Gyro sensor size is the header + N samples (usually 14 or 20 bytes)
 pub const FDB_SIZE_BASIC_SENSOR_DATA: u8 = 15;
 pub const FDB_SIZE_DOCKING_IR: u8 = 3;
 pub const FDB_SIZE_INERTIAL_SENSOR: u8 = 7;
@@ -25,8 +31,14 @@ pub const FDB_SIZE_CLIFF: u8 = 6;
 pub const FDB_SIZE_CURRENT: u8 = 2;
 pub const FDB_SIZE_HARDWARE_VERSION: u8 = 4;
 pub const FDB_SIZE_FIRMWARE_VERSION: u8 = 4;
-pub const FDB_SIZE_RAW_DATA_3_AXIS_GYRO_A: u8 = 14;
-pub const FDB_SIZE_RAW_DATA_3_AXIS_GYRO_B: u8 = 20;
-pub const FDB_SIZE_GENERAL_PURPOSE_OUTPUT: u8 = 16;
+pub const FDB_SIZE_RAW_DATA_3_AXIS_GYRO_HEADER: u8 = 2;
+pub const FDB_SIZE_RAW_DATA_3_AXIS_GYRO_SAMPLE: u8 = 6;
+pub const FDB_SIZE_GENERAL_PURPOSE_INPUT: u8 = 16;
 pub const FDB_SIZE_UNIQUE_DEVICE_IDENTIFIER: u8 = 12;
 pub const FDB_SIZE_CONTROLLER_INFO: u8 = 13;
+
+// These can be used to convert raw values to physical units
+pub const FDB_UNIT_BATTERY_VOLTS: f64 = 0.1;
+pub const FDB_UNIT_INERTIAL_DEG: f64 = 0.01;
+pub const FDB_UNIT_CURRENT_AMPS: f64 = 0.01;
+pub const FDB_UNIT_GYRO_DEG_PER_SEC: f64 = 0.00875;
@@ -0,0 +1,108 @@
+use anyhow::{anyhow, Result};
+
+use crate::tx::KobukiCommand;
+
+// The unit of the note period of the Sound command (s)
+const NOTE_PERIOD_UNIT: f64 = 0.00000275;
+// Equal temperament from A4
+const A4_FREQUENCY: f64 = 440.0;
+const A4_MIDI: i32 = 69;
+// C-1 to B9, about the MIDI note numbers
+pub const MIN_OCTAVE: i32 = -1;
+pub const MAX_OCTAVE: i32 = 9;
+
+impl KobukiCommand {
+    pub fn sound(frequency: f64, duration_ms: u8) -> Result<KobukiCommand> {
+        if duration_ms == 0 {
+            return Err(anyhow!("What duration? 0 ms"));
+        }
+        Ok(KobukiCommand::Sound {
+            note: note_period(frequency)?,
+            duration_ms,
+        })
+    }
+}
+
+// The Sound command takes the period of a note: 1 / (frequency * 0.00000275)
+// so from about 5.6 Hz (65535) to 363 kHz (1).
+pub fn note_period(frequency: f64) -> Result<u16> {
+    let period = (1.0 / (frequency * NOTE_PERIOD_UNIT)).round();
+    if !period.is_finite() || !(1.0..=u16::MAX as f64).contains(&period) {
+        return Err(anyhow!("What frequency? {} Hz", frequency));
+    }
+    Ok(period as u16)
+}
+
+// Scientific pitch notation: C4, C#4, Db4, ... B4 (A4 = 440 Hz)
+pub fn note_frequency(name: &str) -> Result<f64> {
+    let bad_name = || anyhow!("What note? {}", name);
+    let mut chars = name.trim().chars().peekable();
+    let semitone = match chars.next().map(|c| c.to_ascii_uppercase()) {
+        Some('C') => 0,
+        Some('D') => 2,
+        Some('E') => 4,
+        Some('F') => 5,
+        Some('G') => 7,
+        Some('A') => 9,
+        Some('B') => 11,
+        _ => return Err(bad_name()),
+    };
+    let accidental = match chars.peek() {
+        Some('#') => 1,
+        Some('b') => -1,
+        _ => 0,
+    };
+    if accidental != 0 {
+        chars.next();
+    }
+    let octave: i32 = chars.collect::<String>().parse().map_err(|_| bad_name())?;
+    if !(MIN_OCTAVE..=MAX_OCTAVE).contains(&octave) {
+        return Err(bad_name());
+    }
+    Ok(midi_frequency((octave + 1) * 12 + semitone + accidental))
+}
+
+pub fn midi_frequency(midi: i32) -> f64 {
+    A4_FREQUENCY * 2f64.powf((midi - A4_MIDI) as f64 / 12.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frequencies() {
+        let table = [
+            ("A4", 440.0),
+            ("a4", 440.0),
+            ("C4", 261.626),
+            ("C#4", 277.183),
+            ("Db4", 277.183),
+            ("A0", 27.5),
+            ("C-1", 8.176),
+            ("B9", 15804.266),
+        ];
+        for (name, frequency) in table {
+            let f = note_frequency(name).unwrap();
+            assert!((f - frequency).abs() < 1e-3, "{}: {}", name, f);
+        }
+    }
+
+    #[test]
+    fn bad_names() {
+        for name in [
+            "",
+            "H4",
+            "A",
+            "A#",
+            "A4.",
+            "C10",
+            "C-2",
+            "C2147483647",
+            "C-2147483648",
+        ] {
+            let e = note_frequency(name).unwrap_err();
+            assert!(e.to_string().starts_with("What note?"), "{}", name);
+        }
+    }
+}